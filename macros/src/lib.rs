@@ -0,0 +1,464 @@
+//! Procedural macros of the `or-tools` crate, which re-exports them.
+
+use std::collections::HashMap;
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{quote, quote_spanned};
+use syn::{
+    bracketed,
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    punctuated::Punctuated,
+    spanned::Spanned,
+    BinOp, Error, Expr, ExprRange, Ident, LitStr, Result, Stmt, Token, UnOp,
+};
+
+/// Builds a CP-SAT model from a compact, MiniZinc-like syntax.
+///
+/// The macro takes a `CpModelBuilder` (or a reference to it), followed by `=>`
+/// and a list of statements, each terminated by `;`:
+///
+/// - `x in 0..10;` declares an integer variable `x`. As in MiniZinc, both bounds
+///   are included, like with `0..=10`.
+/// - `x, y in [1, 3, 5];` declares integer variables with a set of values.
+/// - `b: bool;` declares a Boolean variable.
+/// - `x + 2 * y <= 10;` adds a linear constraint, with any of `==`, `!=`, `<=`,
+///   `<`, `>=` and `>`.
+/// - `all_different([x, y, z]);` adds a global constraint. The others are
+///   `bool_or`, `bool_and`, `bool_xor`, `at_most_one` and `exactly_one`, which
+///   take a list of literals such as `b` or `!b`, and `implication(a, b)`.
+/// - `b;` or `!b;` fixes a literal.
+/// - `minimize x + 2 * y;` or `maximize ...;` sets the objective.
+///
+/// The variables are declared as local bindings with the same name, available
+/// after the macro. Within the macro, identifiers must refer to variables that
+/// are declared before, which is checked at compile-time. Other Rust values are
+/// inserted with braces, e.g. `x <= {capacity};`, and must be `i64`.
+///
+/// ```ignore
+/// let model = CpModelBuilder::new();
+/// cp_model! { model =>
+///     x, y, z in 0..10;
+///     all_different([x, y, z]);
+///     x + y == {2 * n};
+///     minimize x + 2 * y;
+/// }
+/// let response = model.solve();
+/// println!("x = {}", response.value(&x));
+/// ```
+#[proc_macro]
+pub fn cp_model(input: TokenStream) -> TokenStream {
+    let model = parse_macro_input!(input as CpModel);
+    match model.expand() {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+mod keyword {
+    syn::custom_keyword!(bool);
+    syn::custom_keyword!(maximize);
+    syn::custom_keyword!(minimize);
+}
+
+struct CpModel {
+    builder: Expr,
+    statements: Vec<Statement>,
+}
+
+enum Statement {
+    IntVars { names: Vec<Ident>, domain: Domain },
+    BoolVars { names: Vec<Ident> },
+    Constraint(Expr),
+    Objective { maximize: bool, expr: Expr },
+}
+
+enum Domain {
+    Range { start: Box<Expr>, end: Box<Expr> },
+    Values(Vec<Expr>),
+}
+
+impl Parse for CpModel {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let builder = input.parse()?;
+        input.parse::<Token![=>]>()?;
+
+        let mut statements = Vec::default();
+        while !input.is_empty() {
+            statements.push(input.parse()?);
+            input.parse::<Token![;]>()?;
+        }
+        Ok(Self {
+            builder,
+            statements,
+        })
+    }
+}
+
+impl Parse for Statement {
+    fn parse(input: ParseStream) -> Result<Self> {
+        // A declaration starts with a list of names followed by `in` or `:`.
+        let fork = input.fork();
+        if parse_names(&fork).is_ok() {
+            if fork.peek(Token![in]) {
+                let names = parse_names(input)?;
+                input.parse::<Token![in]>()?;
+                let domain = input.parse()?;
+                return Ok(Self::IntVars { names, domain });
+            }
+            if fork.peek(Token![:]) {
+                let names = parse_names(input)?;
+                input.parse::<Token![:]>()?;
+                input.parse::<keyword::bool>()?;
+                return Ok(Self::BoolVars { names });
+            }
+        }
+
+        if input.peek(keyword::minimize) {
+            input.parse::<keyword::minimize>()?;
+            let expr = input.parse()?;
+            return Ok(Self::Objective {
+                maximize: false,
+                expr,
+            });
+        }
+        if input.peek(keyword::maximize) {
+            input.parse::<keyword::maximize>()?;
+            let expr = input.parse()?;
+            return Ok(Self::Objective {
+                maximize: true,
+                expr,
+            });
+        }
+        input.parse().map(Self::Constraint)
+    }
+}
+
+fn parse_names(input: ParseStream) -> Result<Vec<Ident>> {
+    let mut names = vec![input.parse()?];
+    while input.peek(Token![,]) {
+        input.parse::<Token![,]>()?;
+        names.push(input.parse()?);
+    }
+    Ok(names)
+}
+
+impl Parse for Domain {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(syn::token::Bracket) {
+            let content;
+            bracketed!(content in input);
+            let values = Punctuated::<Expr, Token![,]>::parse_terminated(&content)?;
+            return Ok(Self::Values(values.into_iter().collect()));
+        }
+        match input.parse()? {
+            Expr::Range(ExprRange {
+                start: Some(start),
+                end: Some(end),
+                ..
+            }) => Ok(Self::Range { start, end }),
+            expr => Err(Error::new(
+                expr.span(),
+                "expected a domain, such as `0..10` or `[1, 3, 5]`",
+            )),
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq)]
+enum VarKind {
+    Int,
+    Bool,
+}
+
+/// A linear expression, either made of constants only (of type `i64`), or a
+/// `LinearExpr`.
+enum Linear {
+    Constant(TokenStream2),
+    Expr(TokenStream2),
+}
+
+impl Linear {
+    fn into_expr(self) -> TokenStream2 {
+        match self {
+            Self::Constant(value) => quote!(::or_tools::sat::cp_model::LinearExpr::from(#value)),
+            Self::Expr(expr) => expr,
+        }
+    }
+}
+
+struct Expander {
+    builder: Ident,
+    vars: HashMap<String, VarKind>,
+}
+
+impl CpModel {
+    fn expand(self) -> Result<TokenStream2> {
+        let mut expander = Expander {
+            builder: Ident::new("__cp_model_builder", Span::mixed_site()),
+            vars: HashMap::default(),
+        };
+
+        let builder = &expander.builder;
+        let builder_expr = self.builder;
+        let mut tokens = quote_spanned!(Span::mixed_site()=> let #builder = &(#builder_expr););
+        for statement in self.statements {
+            tokens.extend(expander.expand_statement(statement)?);
+        }
+        Ok(tokens)
+    }
+}
+
+impl Expander {
+    fn expand_statement(&mut self, statement: Statement) -> Result<TokenStream2> {
+        let builder = self.builder.clone();
+        match statement {
+            Statement::IntVars { names, domain } => {
+                let domain = match domain {
+                    Domain::Range { start, end } => quote! {
+                        ::or_tools::util::sorted_interval_list::Domain::new(#start, #end)
+                    },
+                    Domain::Values(values) => quote! {
+                        ::or_tools::util::sorted_interval_list::Domain::from_values(&[#(#values),*])
+                    },
+                };
+                let mut tokens = TokenStream2::default();
+                for name in names {
+                    self.declare(&name, VarKind::Int)?;
+                    let label = LitStr::new(&name.to_string(), name.span());
+                    tokens.extend(quote! {
+                        let #name = #builder.new_int_var(#domain).with_name(#label);
+                    });
+                }
+                Ok(tokens)
+            }
+            Statement::BoolVars { names } => {
+                let mut tokens = TokenStream2::default();
+                for name in names {
+                    self.declare(&name, VarKind::Bool)?;
+                    let label = LitStr::new(&name.to_string(), name.span());
+                    tokens.extend(quote! {
+                        let #name = #builder.new_bool_var().with_name(#label);
+                    });
+                }
+                Ok(tokens)
+            }
+            Statement::Constraint(expr) => self.expand_constraint(&expr),
+            Statement::Objective { maximize, expr } => {
+                let expr = self.linear(&expr)?.into_expr();
+                Ok(if maximize {
+                    quote!(#builder.maximize(#expr);)
+                } else {
+                    quote!(#builder.minimize(#expr);)
+                })
+            }
+        }
+    }
+
+    fn declare(&mut self, name: &Ident, kind: VarKind) -> Result<()> {
+        if self.vars.insert(name.to_string(), kind).is_some() {
+            return Err(Error::new(
+                name.span(),
+                format!("variable `{name}` is already declared"),
+            ));
+        }
+        Ok(())
+    }
+
+    fn expand_constraint(&self, expr: &Expr) -> Result<TokenStream2> {
+        let builder = &self.builder;
+        match expr {
+            Expr::Binary(binary) => {
+                let method = match binary.op {
+                    BinOp::Eq(_) => "add_linear_eq",
+                    BinOp::Ne(_) => "add_linear_ne",
+                    BinOp::Le(_) => "add_linear_le",
+                    BinOp::Lt(_) => "add_linear_lt",
+                    BinOp::Ge(_) => "add_linear_ge",
+                    BinOp::Gt(_) => "add_linear_gt",
+                    _ => {
+                        return Err(Error::new(
+                            binary.op.span(),
+                            "expected a comparison: `==`, `!=`, `<=`, `<`, `>=` or `>`",
+                        ))
+                    }
+                };
+                let method = Ident::new(method, binary.op.span());
+                let lhs = self.linear(&binary.left)?.into_expr();
+                let rhs = self.linear(&binary.right)?.into_expr();
+                Ok(quote!(#builder.#method(#lhs, #rhs);))
+            }
+            Expr::Call(call) => {
+                let name = match &*call.func {
+                    Expr::Path(path) => path.path.get_ident(),
+                    _ => None,
+                };
+                let Some(name) = name else {
+                    return Err(Error::new(call.func.span(), "expected a constraint name"));
+                };
+                let args: Vec<&Expr> = call.args.iter().collect();
+                match (name.to_string().as_str(), args.as_slice()) {
+                    ("all_different", [Expr::Array(array)]) => {
+                        let exprs = array
+                            .elems
+                            .iter()
+                            .map(|expr| self.linear(expr).map(Linear::into_expr))
+                            .collect::<Result<Vec<_>>>()?;
+                        Ok(quote!(#builder.add_all_different([#(#exprs),*]);))
+                    }
+                    (
+                        "bool_or" | "bool_and" | "bool_xor" | "at_most_one" | "exactly_one",
+                        [Expr::Array(array)],
+                    ) => {
+                        let method = Ident::new(&format!("add_{name}"), name.span());
+                        let literals = array
+                            .elems
+                            .iter()
+                            .map(|expr| self.literal(expr))
+                            .collect::<Result<Vec<_>>>()?;
+                        Ok(quote!(#builder.#method(&[#(#literals),*]);))
+                    }
+                    ("implication", [a, b]) => {
+                        let a = self.literal(a)?;
+                        let b = self.literal(b)?;
+                        Ok(quote!(#builder.add_implication(#a, #b);))
+                    }
+                    (
+                        "all_different" | "bool_or" | "bool_and" | "bool_xor" | "at_most_one"
+                        | "exactly_one",
+                        _,
+                    ) => Err(Error::new(
+                        call.span(),
+                        format!("`{name}` expects a list, such as `{name}([a, b])`"),
+                    )),
+                    ("implication", _) => Err(Error::new(
+                        call.span(),
+                        "`implication` expects two literals, such as `implication(a, !b)`",
+                    )),
+                    _ => Err(Error::new(
+                        name.span(),
+                        format!(
+                            "unknown constraint `{name}`, expected one of `all_different`, \
+                             `bool_or`, `bool_and`, `bool_xor`, `at_most_one`, `exactly_one` \
+                             and `implication`"
+                        ),
+                    )),
+                }
+            }
+            Expr::Path(_) | Expr::Unary(_) => {
+                let literal = self.literal(expr)?;
+                Ok(quote!(#builder.add_bool_and(&[#literal]);))
+            }
+            _ => Err(Error::new(
+                expr.span(),
+                "expected a constraint, such as `x + y <= 10` or `all_different([x, y])`",
+            )),
+        }
+    }
+
+    fn var<'a>(&self, expr: &'a Expr) -> Result<Option<(&'a Ident, VarKind)>> {
+        let Expr::Path(path) = expr else {
+            return Ok(None);
+        };
+        let Some(name) = path.path.get_ident() else {
+            return Err(Error::new(expr.span(), "expected a variable name"));
+        };
+        match self.vars.get(&name.to_string()) {
+            Some(kind) => Ok(Some((name, *kind))),
+            None => Err(Error::new(
+                name.span(),
+                format!(
+                    "unknown variable `{name}`; Rust values must be put in braces, such as \
+                     `{{{name}}}`"
+                ),
+            )),
+        }
+    }
+
+    fn literal(&self, expr: &Expr) -> Result<TokenStream2> {
+        match expr {
+            Expr::Unary(unary) if matches!(unary.op, UnOp::Not(_)) => {
+                let literal = self.literal(&unary.expr)?;
+                Ok(quote!(!#literal))
+            }
+            Expr::Paren(paren) => self.literal(&paren.expr),
+            _ => match self.var(expr)? {
+                Some((name, VarKind::Bool)) => Ok(quote!(#name)),
+                Some((name, VarKind::Int)) => Err(Error::new(
+                    name.span(),
+                    format!("`{name}` is not a Boolean variable"),
+                )),
+                None => Err(Error::new(
+                    expr.span(),
+                    "expected a literal, such as `b` or `!b`",
+                )),
+            },
+        }
+    }
+
+    fn linear(&self, expr: &Expr) -> Result<Linear> {
+        match expr {
+            Expr::Lit(lit) => Ok(Linear::Constant(quote!(#lit))),
+            Expr::Block(block) => Ok(Linear::Constant(match block.block.stmts.as_slice() {
+                // Unwrapped, as the braces of a single value are unnecessary for
+                // the compiler.
+                [Stmt::Expr(expr, None)] => quote!((#expr)),
+                _ => quote!(#block),
+            })),
+            Expr::Paren(paren) => self.linear(&paren.expr),
+            Expr::Path(_) => match self.var(expr)? {
+                Some((name, _)) => Ok(Linear::Expr(
+                    quote!(::or_tools::sat::cp_model::LinearExpr::from(#name)),
+                )),
+                None => unreachable!("paths are either variables or errors"),
+            },
+            Expr::Unary(unary) if matches!(unary.op, UnOp::Neg(_)) => {
+                Ok(match self.linear(&unary.expr)? {
+                    Linear::Constant(value) => Linear::Constant(quote!((-(#value)))),
+                    Linear::Expr(expr) => Linear::Expr(quote!((-(#expr)))),
+                })
+            }
+            Expr::Binary(binary) => {
+                let lhs = self.linear(&binary.left)?;
+                let rhs = self.linear(&binary.right)?;
+                match binary.op {
+                    BinOp::Add(_) | BinOp::Sub(_) => {
+                        let op = &binary.op;
+                        Ok(match (lhs, rhs) {
+                            (Linear::Constant(lhs), Linear::Constant(rhs)) => {
+                                Linear::Constant(quote!((#lhs #op #rhs)))
+                            }
+                            (lhs, rhs) => {
+                                let lhs = lhs.into_expr();
+                                let rhs = rhs.into_expr();
+                                Linear::Expr(quote!((#lhs #op #rhs)))
+                            }
+                        })
+                    }
+                    BinOp::Mul(_) => match (lhs, rhs) {
+                        (Linear::Constant(lhs), Linear::Constant(rhs)) => {
+                            Ok(Linear::Constant(quote!((#lhs * #rhs))))
+                        }
+                        (Linear::Constant(coefficient), Linear::Expr(expr))
+                        | (Linear::Expr(expr), Linear::Constant(coefficient)) => {
+                            Ok(Linear::Expr(quote!((#expr * #coefficient))))
+                        }
+                        (Linear::Expr(_), Linear::Expr(_)) => Err(Error::new(
+                            binary.span(),
+                            "the product of two variables is not linear",
+                        )),
+                    },
+                    _ => Err(Error::new(
+                        binary.op.span(),
+                        "expected a linear expression, with `+`, `-` or `*` by a constant",
+                    )),
+                }
+            }
+            _ => Err(Error::new(
+                expr.span(),
+                "expected a linear expression, such as `x + 2 * y - 3`",
+            )),
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use or_tools_macros::cp_model;
+
+fn main() {
+    let model = ();
+    cp_model! { model =>
+        x in 0..10;
+        x + y <= 10;
+    }
+}
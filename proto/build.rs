@@ -0,0 +1,142 @@
+use std::fs;
+
+use prost::Message as _;
+use prost_types::{source_code_info::Location, FileDescriptorSet, SourceCodeInfo};
+use protobuf::Message as _;
+
+const INCLUDE: &str = "proto";
+const PROTOS: &[&str] = &[
+    "proto/ortools/sat/cp_model.proto",
+    "proto/ortools/sat/sat_parameters.proto",
+];
+
+fn main() {
+    println!("cargo:rerun-if-changed=./build.rs");
+    for proto in PROTOS {
+        println!("cargo:rerun-if-changed=./{proto}");
+    }
+
+    // Parse the vendored files in pure Rust, so that building does not
+    // require protoc.
+    let file_descriptor_set = ::protobuf_parse::Parser::new()
+        .pure()
+        .include(INCLUDE)
+        .inputs(PROTOS)
+        .file_descriptor_set()
+        .expect("failed to parse the proto files");
+    let bytes = file_descriptor_set
+        .write_to_bytes()
+        .expect("failed to encode the file descriptor set");
+    let mut file_descriptor_set = FileDescriptorSet::decode(bytes.as_slice())
+        .expect("failed to decode the file descriptor set");
+
+    // The pure parser drops the comments, so bring them back for the docs.
+    for file in &mut file_descriptor_set.file {
+        let path = format!("{INCLUDE}/{}", file.name());
+        let source = fs::read_to_string(&path).expect("failed to read the proto file");
+        file.source_code_info = Some(SourceCodeInfo {
+            location: comment_locations(&source),
+        });
+    }
+
+    // Generate
+    ::prost_build::Config::new()
+        .compile_fds(file_descriptor_set)
+        .expect("failed to generate the proto types");
+}
+
+/// Returns the locations of the messages, enums, oneofs, fields and enum values
+/// of a proto file, with their leading and trailing comments.
+///
+/// This only understands the layout of the vendored files, with one
+/// declaration per line.
+fn comment_locations(source: &str) -> Vec<Location> {
+    // Field numbers of the nested declarations in `FileDescriptorProto`,
+    // `DescriptorProto` and `EnumDescriptorProto`.
+    const FILE_MESSAGE: i32 = 4;
+    const FILE_ENUM: i32 = 5;
+    const MESSAGE_FIELD: i32 = 2;
+    const MESSAGE_NESTED: i32 = 3;
+    const MESSAGE_ENUM: i32 = 4;
+    const MESSAGE_ONEOF: i32 = 8;
+    const ENUM_VALUE: i32 = 2;
+
+    #[derive(Default)]
+    struct Scope {
+        path: Vec<i32>,
+        is_enum: bool,
+        is_oneof: bool,
+        num_fields: i32,
+        num_messages: i32,
+        num_enums: i32,
+        num_oneofs: i32,
+    }
+
+    let mut locations = Vec::new();
+    let mut scopes = vec![Scope::default()];
+    let mut comment = String::new();
+    for line in source.lines().map(str::trim) {
+        if let Some(text) = line.strip_prefix("//") {
+            comment.push_str(text);
+            comment.push('\n');
+            continue;
+        }
+        let leading = std::mem::take(&mut comment);
+        let (code, trailing) = match line.split_once("//") {
+            Some((code, text)) => (code.trim(), format!("{text}\n")),
+            None => (line, String::new()),
+        };
+
+        // Oneof fields belong to the enclosing message.
+        let owner = scopes.len() - 1 - usize::from(scopes.last().unwrap().is_oneof);
+        let path = if let Some(block) = code.strip_suffix('{') {
+            let kind = block.split_whitespace().next().unwrap_or_default();
+            let is_file = scopes.len() == 1;
+            let parent = &mut scopes[owner];
+            let (tag, counter) = match kind {
+                "message" if is_file => (FILE_MESSAGE, &mut parent.num_messages),
+                "message" => (MESSAGE_NESTED, &mut parent.num_messages),
+                "enum" if is_file => (FILE_ENUM, &mut parent.num_enums),
+                "enum" => (MESSAGE_ENUM, &mut parent.num_enums),
+                "oneof" => (MESSAGE_ONEOF, &mut parent.num_oneofs),
+                _ => panic!("unexpected declaration: {line}"),
+            };
+            let mut path = parent.path.clone();
+            path.extend([tag, *counter]);
+            *counter += 1;
+            scopes.push(Scope {
+                path: path.clone(),
+                is_enum: kind == "enum",
+                is_oneof: kind == "oneof",
+                ..Scope::default()
+            });
+            Some(path)
+        } else if code == "}" {
+            scopes.pop();
+            None
+        } else if code.ends_with(';') && code.contains('=') && scopes.len() > 1 {
+            let parent = &mut scopes[owner];
+            let tag = if parent.is_enum {
+                ENUM_VALUE
+            } else {
+                MESSAGE_FIELD
+            };
+            let mut path = parent.path.clone();
+            path.extend([tag, parent.num_fields]);
+            parent.num_fields += 1;
+            Some(path)
+        } else {
+            None
+        };
+
+        if let Some(path) = path {
+            locations.push(Location {
+                path,
+                leading_comments: Some(leading).filter(|text| !text.is_empty()),
+                trailing_comments: Some(trailing).filter(|text| !text.is_empty()),
+                ..Location::default()
+            });
+        }
+    }
+    locations
+}
@@ -0,0 +1,11 @@
+//! Pure-Rust types of the Google OR-Tools protobuf messages, built on `prost`.
+//!
+//! They do not depend on the native library, so that models can be built,
+//! inspected and sent to a solver service by programs that do not link it. The
+//! messages are encoded and decoded with the `prost::Message` trait, and the
+//! `or-tools` crate converts them from and to its native types through their
+//! serialized bytes.
+
+pub use prost;
+
+pub mod sat;
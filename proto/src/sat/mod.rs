@@ -0,0 +1,10 @@
+//! Messages of the `operations_research.sat` protobuf package.
+//!
+//! They are generated at build time from the vendored `cp_model.proto` and
+//! `sat_parameters.proto` of OR-Tools v9.7.
+
+// The docs are the comments of the proto files, which are not written for
+// rustdoc.
+#![allow(rustdoc::broken_intra_doc_links)]
+
+include!(concat!(env!("OUT_DIR"), "/operations_research.sat.rs"));
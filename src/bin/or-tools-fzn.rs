@@ -0,0 +1,82 @@
+//! FlatZinc front-end of the CP-SAT solver, following the MiniZinc solver
+//! interface.
+//!
+//! Usage: `or-tools-fzn [-a] [-f] [-p <threads>] [-t <milliseconds>] <model.fzn>`
+//!
+//! To use it from MiniZinc, install the binary in `<prefix>/bin` and copy the
+//! `share/minizinc` directory of this crate to `<prefix>/share/minizinc`. The
+//! solver configuration `or-tools-fzn.msc` then selects the `or-tools` library,
+//! which declares the constraints supported natively.
+
+use std::{env, io, process};
+
+use or_tools::flatzinc::{
+    cp_model_fz_solver::{solve_fz_with_cp_model, FlatzincSatParameters},
+    parser::parse_flatzinc_file,
+};
+
+const USAGE: &str = "usage: or-tools-fzn [-a] [-f] [-p <threads>] [-t <milliseconds>] <model.fzn>
+
+options:
+    -a    print all the solutions, or all the intermediate solutions when optimizing
+    -f    ignore the search annotations
+    -p    number of parallel workers
+    -t    time limit in milliseconds";
+
+fn main() {
+    let mut parameters = FlatzincSatParameters::default();
+    let mut path = None;
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-a" | "--all-solutions" => parameters.display_all_solutions = true,
+            "-f" | "--free-search" => parameters.use_free_search = true,
+            "-p" | "--parallel" => {
+                parameters.number_of_threads = parse_value::<u16>(&arg, args.next()).into();
+            }
+            "-t" | "--time-limit" => {
+                let milliseconds: u64 = parse_value(&arg, args.next());
+                parameters.max_time_in_seconds = Some(milliseconds as f64 / 1000.0);
+            }
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return;
+            }
+            _ if arg.starts_with('-') || path.is_some() => {
+                usage_error(&format!("unexpected argument {arg:?}"));
+            }
+            _ => path = Some(arg),
+        }
+    }
+    let Some(path) = path else {
+        usage_error("missing FlatZinc file");
+    };
+
+    let model = match parse_flatzinc_file(&path) {
+        Ok(model) => model,
+        Err(error) => {
+            eprintln!("{path}: {error}");
+            process::exit(1);
+        }
+    };
+    if let Err(error) = solve_fz_with_cp_model(&model, &parameters, io::stdout().lock()) {
+        eprintln!("{path}: {error}");
+        process::exit(1);
+    }
+}
+
+fn parse_value<T>(flag: &str, value: Option<String>) -> T
+where
+    T: std::str::FromStr,
+{
+    match value.as_deref().map(str::parse) {
+        Some(Ok(value)) => value,
+        _ => usage_error(&format!("{flag} expects a non-negative integer")),
+    }
+}
+
+fn usage_error(message: &str) -> ! {
+    eprintln!("error: {message}\n\n{USAGE}");
+    process::exit(2);
+}
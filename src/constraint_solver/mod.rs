@@ -1,5 +1,3 @@
-use crate::sat::cp_model::{Constraint, IntVar};
-
 pub mod routing;
 pub mod routing_enums;
 pub mod routing_index_manager;
@@ -112,6 +110,23 @@ impl Solver {
     }
 }
 
+cpp_class!(
+    /// A constraint is the main modeling object. It provides two methods:
+    ///   - Post() is responsible for creating the demons and attaching them to
+    ///     immediate demons().
+    ///   - InitialPropagate() is called once just after Post and performs
+    ///     the initial propagation. The subsequent propagations will be performed
+    ///     by the demons Posted during the post() method.
+    pub unsafe struct Constraint as "operations_research::Constraint"
+);
+
+cpp_class!(
+    /// The class IntVar is a subset of IntExpr. In addition to the
+    /// IntExpr protocol, it offers persistence, removing and delaying
+    /// and reaction to domain modification.
+    pub unsafe struct IntVar as "operations_research::IntVar"
+);
+
 cpp_class!(
     /// An Assignment is a variable -> domains mapping, used
     /// to report solutions to the user.
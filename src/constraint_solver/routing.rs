@@ -2,12 +2,10 @@ use std::{ffi::c_int, marker::PhantomData, mem::transmute};
 
 use libffi::high::Closure2;
 
-use crate::sat::cp_model::IntVar;
-
 use super::{
     routing_index_manager::RoutingIndexManager,
     routing_parameters::{RoutingModelParameters, RoutingSearchParameters},
-    Solver,
+    IntVar, Solver,
 };
 
 // IMPORT CXX LIBRARY
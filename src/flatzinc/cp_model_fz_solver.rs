@@ -0,0 +1,745 @@
+use std::{
+    collections::HashMap,
+    io::{self, Write},
+    ops::{ControlFlow, RangeInclusive},
+};
+
+use crate::{
+    sat::{
+        cp_model::{
+            BoolVar, CpModelBuilder, CpSolverResponse, CpSolverStatus, DomainReductionStrategy,
+            IntVar, LinearExpr, VariableSelectionStrategy,
+        },
+        sat_parameters::SatParameters,
+    },
+    util::sorted_interval_list::Domain,
+};
+
+use super::model::{self, Declaration, Expr, Goal, Model};
+
+/// Largest exponent of `int_pow`, which is expanded into a product with one
+/// factor per unit of the exponent. Any larger power of an integer other than
+/// -1, 0 and 1 overflows an `i64` anyway.
+const MAX_POW_EXPONENT: i64 = 62;
+
+/// Parameters of the FlatZinc front-end, following the standard flags of the
+/// MiniZinc solver interface.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FlatzincSatParameters {
+    /// `-a`: print all the solutions of a satisfaction problem, or all the
+    /// intermediate solutions of an optimization problem
+    pub display_all_solutions: bool,
+    /// `-f`: ignore the search annotations
+    pub use_free_search: bool,
+    /// `-p`: number of parallel workers, 0 to let the solver decide
+    pub number_of_threads: i32,
+    /// `-t`: time limit in seconds, if any
+    pub max_time_in_seconds: Option<f64>,
+}
+
+/// Solves a FlatZinc model with CP-SAT, and writes the solutions in the
+/// MiniZinc output protocol.
+///
+/// Each solution prints the output variables followed by `----------`. The
+/// search ends with `==========` once it is complete, i.e. when all the
+/// solutions have been enumerated or when the last one is proven optimal, with
+/// `=====UNSATISFIABLE=====` when there is no solution, or with
+/// `=====UNKNOWN=====` when the limits are reached before the first one.
+///
+/// Returns an `InvalidData` error if the model uses an unsupported feature,
+/// such as float or set variables.
+pub fn solve_fz_with_cp_model(
+    fz_model: &Model,
+    parameters: &FlatzincSatParameters,
+    mut writer: impl Write,
+) -> io::Result<()> {
+    let model = CpModelBuilder::default();
+    let mut loader = Loader {
+        model: &model,
+        values: HashMap::default(),
+        true_literal: None,
+    };
+    let outputs = loader.load(fz_model, parameters).map_err(invalid_data)?;
+
+    let is_optimization = !matches!(fz_model.solve.goal, Goal::Satisfy);
+    let mut sat_parameters = SatParameters::new();
+    if parameters.number_of_threads > 0 {
+        sat_parameters.set_num_workers(parameters.number_of_threads);
+    }
+    if let Some(max_time_in_seconds) = parameters.max_time_in_seconds {
+        sat_parameters.set_max_time_in_seconds(max_time_in_seconds);
+    }
+    if parameters.display_all_solutions && !is_optimization {
+        // Enumerating all the solutions is only supported by a single worker.
+        sat_parameters.set_enumerate_all_solutions(true);
+        sat_parameters.set_num_workers(1);
+    }
+
+    let mut num_solutions = 0;
+    let response = if parameters.display_all_solutions {
+        let mut result = Ok(());
+        let response = model.solve_with_observer(&sat_parameters, |response| {
+            result = write_solution(&outputs, response, &mut writer);
+            num_solutions += 1;
+            if result.is_ok() {
+                ControlFlow::Continue(())
+            } else {
+                ControlFlow::Break(())
+            }
+        });
+        result?;
+        response
+    } else {
+        let response = model.solve_with_parameters(&sat_parameters);
+        if matches!(
+            response.status(),
+            CpSolverStatus::Optimal | CpSolverStatus::Feasible
+        ) {
+            write_solution(&outputs, &response, &mut writer)?;
+            num_solutions += 1;
+        }
+        response
+    };
+
+    match response.status() {
+        CpSolverStatus::Optimal if is_optimization || parameters.display_all_solutions => {
+            writeln!(writer, "==========")
+        }
+        CpSolverStatus::Optimal | CpSolverStatus::Feasible => Ok(()),
+        CpSolverStatus::Infeasible => writeln!(writer, "=====UNSATISFIABLE====="),
+        CpSolverStatus::Unknown if num_solutions == 0 => writeln!(writer, "=====UNKNOWN====="),
+        CpSolverStatus::Unknown => Ok(()),
+        CpSolverStatus::ModelInvalid => writeln!(writer, "=====ERROR====="),
+    }
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// The value of a FlatZinc expression in the CP-SAT model.
+#[derive(Clone)]
+enum Value<'model> {
+    Bool(bool),
+    Int(i64),
+    Set(Vec<RangeInclusive<i64>>),
+    BoolVar(BoolVar<'model>),
+    IntVar(IntVar<'model>),
+    Array(Vec<Value<'model>>),
+}
+
+/// An output variable or array, as given by the `output_var` and
+/// `output_array` annotations.
+struct Output<'model> {
+    name: String,
+    value: Value<'model>,
+    /// The index sets of an output array, e.g. `1..2, 1..3`
+    dimensions: Option<Vec<RangeInclusive<i64>>>,
+}
+
+struct Loader<'model> {
+    model: &'model CpModelBuilder,
+    values: HashMap<String, Value<'model>>,
+    true_literal: Option<BoolVar<'model>>,
+}
+
+impl<'model> Loader<'model> {
+    fn load(
+        &mut self,
+        fz_model: &Model,
+        parameters: &FlatzincSatParameters,
+    ) -> Result<Vec<Output<'model>>, String> {
+        let mut outputs = Vec::default();
+        for declaration in &fz_model.declarations {
+            let value = self.load_declaration(declaration)?;
+            for annotation in &declaration.annotations {
+                match annotation {
+                    Expr::Ident(name) if name == "output_var" => outputs.push(Output {
+                        name: declaration.name.clone(),
+                        value: value.clone(),
+                        dimensions: None,
+                    }),
+                    Expr::Call(name, args) if name == "output_array" => {
+                        let dimensions = match args.as_slice() {
+                            [Expr::Array(sets)] => sets
+                                .iter()
+                                .map(|set| match set {
+                                    Expr::Set(set) if set.len() == 1 => Ok(set[0].clone()),
+                                    _ => {
+                                        Err(format!("invalid output_array of {}", declaration.name))
+                                    }
+                                })
+                                .collect::<Result<_, _>>()?,
+                            _ => {
+                                return Err(format!("invalid output_array of {}", declaration.name))
+                            }
+                        };
+                        outputs.push(Output {
+                            name: declaration.name.clone(),
+                            value: value.clone(),
+                            dimensions: Some(dimensions),
+                        });
+                    }
+                    _ => {}
+                }
+            }
+            self.values.insert(declaration.name.clone(), value);
+        }
+
+        for constraint in &fz_model.constraints {
+            self.load_constraint(&constraint.name, &constraint.args)
+                .map_err(|error| format!("constraint {}: {error}", constraint.name))?;
+        }
+
+        match &fz_model.solve.goal {
+            Goal::Satisfy => {}
+            Goal::Minimize(expr) => {
+                let objective = self.linear(&self.resolve(expr)?)?;
+                self.model.minimize(objective);
+            }
+            Goal::Maximize(expr) => {
+                let objective = self.linear(&self.resolve(expr)?)?;
+                self.model.maximize(objective);
+            }
+        }
+        if !parameters.use_free_search {
+            for annotation in &fz_model.solve.annotations {
+                self.load_search_annotation(annotation)?;
+            }
+        }
+        Ok(outputs)
+    }
+
+    fn load_declaration(&mut self, declaration: &Declaration) -> Result<Value<'model>, String> {
+        let name = &declaration.name;
+        if !declaration.is_var {
+            let value = declaration.value.as_ref().expect("parameters have a value");
+            return self.resolve(value);
+        }
+        if declaration.is_array {
+            return match &declaration.value {
+                Some(value) => self.resolve(value),
+                None => Err(format!("array {name} has no value")),
+            };
+        }
+
+        let value = match &declaration.value {
+            Some(value) => self.resolve(value)?,
+            None => match &declaration.domain {
+                model::Domain::Bool => {
+                    return Ok(Value::BoolVar(self.model.new_bool_var().with_name(name)));
+                }
+                model::Domain::Int(intervals) => {
+                    let domain = match intervals {
+                        Some(intervals) => Domain::from_intervals(intervals),
+                        None => Domain::new(i32::MIN.into(), i32::MAX.into()),
+                    };
+                    return Ok(Value::IntVar(
+                        self.model.new_int_var(domain).with_name(name),
+                    ));
+                }
+                model::Domain::Float => {
+                    return Err(format!("float variable {name} is not supported"))
+                }
+                model::Domain::IntSet => {
+                    return Err(format!("set variable {name} is not supported"))
+                }
+            },
+        };
+
+        // A variable with a value is an alias, whose domain still holds.
+        if let model::Domain::Int(Some(intervals)) = &declaration.domain {
+            let expr = self.linear(&value)?;
+            self.model
+                .add_linear_in_domain(expr, Domain::from_intervals(intervals));
+        }
+        Ok(value)
+    }
+
+    fn resolve(&self, expr: &Expr) -> Result<Value<'model>, String> {
+        match expr {
+            Expr::Bool(value) => Ok(Value::Bool(*value)),
+            Expr::Int(value) => Ok(Value::Int(*value)),
+            Expr::Set(intervals) => Ok(Value::Set(intervals.clone())),
+            Expr::Ident(name) => self
+                .values
+                .get(name)
+                .cloned()
+                .ok_or_else(|| format!("unknown identifier {name}")),
+            Expr::ArrayAccess(name, index) => match self.values.get(name) {
+                Some(Value::Array(values)) => usize::try_from(*index - 1)
+                    .ok()
+                    .and_then(|index| values.get(index))
+                    .cloned()
+                    .ok_or_else(|| format!("index {index} out of bounds of {name}")),
+                Some(_) => Err(format!("{name} is not an array")),
+                None => Err(format!("unknown identifier {name}")),
+            },
+            Expr::Array(exprs) => exprs
+                .iter()
+                .map(|expr| self.resolve(expr))
+                .collect::<Result<_, _>>()
+                .map(Value::Array),
+            Expr::Float(_) => Err("floats are not supported".into()),
+            Expr::String(_) | Expr::Call(..) => Err(format!("unexpected expression {expr:?}")),
+        }
+    }
+
+    fn linear(&self, value: &Value<'model>) -> Result<LinearExpr, String> {
+        match value {
+            Value::Bool(value) => Ok(i64::from(*value).into()),
+            Value::Int(value) => Ok((*value).into()),
+            Value::BoolVar(var) => Ok((*var).into()),
+            Value::IntVar(var) => Ok((*var).into()),
+            Value::Set(_) | Value::Array(_) => Err("expected an integer expression".into()),
+        }
+    }
+
+    fn int_var(&self, value: &Value<'model>) -> Result<IntVar<'model>, String> {
+        match value {
+            Value::Bool(value) => Ok(self.model.new_constant(i64::from(*value))),
+            Value::Int(value) => Ok(self.model.new_constant(*value)),
+            Value::BoolVar(var) => Ok((*var).into()),
+            Value::IntVar(var) => Ok(*var),
+            Value::Set(_) | Value::Array(_) => Err("expected an integer variable".into()),
+        }
+    }
+
+    fn literal(&mut self, value: &Value<'model>) -> Result<BoolVar<'model>, String> {
+        match value {
+            Value::Bool(value) => {
+                let model = self.model;
+                let true_literal = *self.true_literal.get_or_insert_with(|| {
+                    let literal = model.new_bool_var();
+                    model.add_bool_or(&[literal]);
+                    literal
+                });
+                Ok(if *value { true_literal } else { !true_literal })
+            }
+            Value::BoolVar(var) => Ok(*var),
+            _ => Err("expected a Boolean expression".into()),
+        }
+    }
+
+    fn array<'a>(&self, value: &'a Value<'model>) -> Result<&'a [Value<'model>], String> {
+        match value {
+            Value::Array(values) => Ok(values),
+            _ => Err("expected an array".into()),
+        }
+    }
+
+    fn linear_array(&self, value: &Value<'model>) -> Result<Vec<LinearExpr>, String> {
+        self.array(value)?
+            .iter()
+            .map(|value| self.linear(value))
+            .collect()
+    }
+
+    fn literal_array(&mut self, value: &Value<'model>) -> Result<Vec<BoolVar<'model>>, String> {
+        self.array(value)?
+            .iter()
+            .map(|value| self.literal(value))
+            .collect()
+    }
+
+    fn constant_array(&self, value: &Value<'model>) -> Result<Vec<i64>, String> {
+        self.array(value)?
+            .iter()
+            .map(|value| match value {
+                Value::Bool(value) => Ok(i64::from(*value)),
+                Value::Int(value) => Ok(*value),
+                _ => Err("expected an array of constants".into()),
+            })
+            .collect()
+    }
+
+    /// Returns the expression `sum(coefficients[i] * vars[i])`, from the
+    /// arguments of the `*_lin_*` constraints.
+    fn scalar_product(
+        &self,
+        coefficients: &Value<'model>,
+        vars: &Value<'model>,
+    ) -> Result<LinearExpr, String> {
+        let coefficients = self.constant_array(coefficients)?;
+        let vars = self.linear_array(vars)?;
+        if coefficients.len() != vars.len() {
+            return Err("coefficients and variables have different lengths".into());
+        }
+        Ok(vars
+            .into_iter()
+            .zip(coefficients)
+            .map(|(var, coefficient)| var * coefficient)
+            .sum())
+    }
+
+    /// Returns the zero-based index variable of an element constraint, whose
+    /// FlatZinc index is one-based.
+    fn element_index(&self, index: &Value<'model>, len: usize) -> Result<IntVar<'model>, String> {
+        let index = self.linear(index)?;
+        let index0 = self.model.new_int_var(Domain::new(0, len as i64 - 1));
+        self.model.add_linear_eq(index0, index - 1);
+        Ok(index0)
+    }
+
+    fn load_constraint(&mut self, name: &str, args: &[Expr]) -> Result<(), String> {
+        let args: Vec<Value<'model>> = args
+            .iter()
+            .map(|arg| self.resolve(arg))
+            .collect::<Result<_, _>>()?;
+        let model = self.model;
+
+        // Comparisons, possibly reified (`_reif`) or half-reified (`_imp`).
+        let (base, reification) = if let Some(base) = name.strip_suffix("_reif") {
+            (base, Some(true))
+        } else if let Some(base) = name.strip_suffix("_imp") {
+            (base, Some(false))
+        } else {
+            (name, None)
+        };
+        let comparison = match (base, args.as_slice()) {
+            (
+                "int_eq" | "int_ne" | "int_le" | "int_lt" | "bool_eq" | "bool_le" | "bool_lt",
+                [a, b, ..],
+            ) => Some((&base[base.len() - 2..], self.linear(a)?, self.linear(b)?)),
+            (
+                "int_lin_eq" | "int_lin_ne" | "int_lin_le" | "int_lin_lt" | "bool_lin_eq"
+                | "bool_lin_le",
+                [coefficients, vars, rhs, ..],
+            ) => Some((
+                &base[base.len() - 2..],
+                self.scalar_product(coefficients, vars)?,
+                self.linear(rhs)?,
+            )),
+            ("set_in", [x, Value::Set(intervals), ..]) => {
+                let x = self.linear(x)?;
+                let domain = Domain::from_intervals(intervals);
+                let enforcement = match reification {
+                    Some(_) => vec![self.literal(args.last().unwrap_or(&Value::Bool(true)))?],
+                    None => Vec::default(),
+                };
+                model
+                    .add_linear_in_domain(x.clone(), domain.clone())
+                    .only_enforce_if(&enforcement);
+                if reification == Some(true) {
+                    model
+                        .add_linear_in_domain(x, domain.complement())
+                        .only_enforce_if(&[!enforcement[0]]);
+                }
+                return Ok(());
+            }
+            _ => None,
+        };
+        if let Some((operator, lhs, rhs)) = comparison {
+            let expected_len = if base.contains("_lin_") { 3 } else { 2 };
+            let enforcement = match reification {
+                Some(_) if args.len() == expected_len + 1 => {
+                    Some(self.literal(&args[expected_len])?)
+                }
+                None if args.len() == expected_len => None,
+                _ => return Err("wrong number of arguments".into()),
+            };
+            let add_comparison = |operator: &str, lhs: LinearExpr, rhs: LinearExpr| match operator {
+                "eq" => model.add_linear_eq(lhs, rhs),
+                "ne" => model.add_linear_ne(lhs, rhs),
+                "le" => model.add_linear_le(lhs, rhs),
+                _ => model.add_linear_lt(lhs, rhs),
+            };
+            match enforcement {
+                None => {
+                    add_comparison(operator, lhs, rhs);
+                }
+                Some(literal) => {
+                    add_comparison(operator, lhs.clone(), rhs.clone()).only_enforce_if(&[literal]);
+                    if reification == Some(true) {
+                        // The negation of `lhs op rhs` is `rhs negated_op lhs`.
+                        let (negated_operator, lhs, rhs) = match operator {
+                            "eq" => ("ne", lhs, rhs),
+                            "ne" => ("eq", lhs, rhs),
+                            "le" => ("lt", rhs, lhs),
+                            _ => ("le", rhs, lhs),
+                        };
+                        add_comparison(negated_operator, lhs, rhs).only_enforce_if(&[!literal]);
+                    }
+                }
+            }
+            return Ok(());
+        }
+
+        match (name, args.as_slice()) {
+            ("int_plus", [a, b, c]) => {
+                model.add_linear_eq(self.linear(a)? + self.linear(b)?, self.linear(c)?);
+            }
+            ("int_times", [a, b, c]) => {
+                model.add_multiplication_equality(
+                    self.linear(c)?,
+                    [self.linear(a)?, self.linear(b)?],
+                );
+            }
+            ("int_div", [a, b, c]) => {
+                model.add_division_equality(self.linear(c)?, self.linear(a)?, self.linear(b)?);
+            }
+            ("int_mod", [a, b, c]) => {
+                model.add_modulo_equality(self.linear(c)?, self.linear(a)?, self.linear(b)?);
+            }
+            ("int_pow", [a, b, c]) => {
+                let exponent = match b {
+                    Value::Int(exponent) if *exponent >= 0 => *exponent,
+                    _ => return Err("exponent must be a non-negative constant".into()),
+                };
+                if exponent > MAX_POW_EXPONENT {
+                    return Err(format!("exponent {exponent} is too large"));
+                }
+                let a = self.linear(a)?;
+                let c = self.linear(c)?;
+                match exponent {
+                    0 => model.add_linear_eq(c, 1_i64),
+                    1 => model.add_linear_eq(c, a),
+                    _ => model.add_multiplication_equality(c, vec![a; exponent as usize]),
+                };
+            }
+            ("int_abs", [a, b]) => {
+                model.add_abs_equality(self.linear(b)?, self.linear(a)?);
+            }
+            ("int_min", [a, b, c]) => {
+                model.add_min_equality(self.linear(c)?, [self.linear(a)?, self.linear(b)?]);
+            }
+            ("int_max", [a, b, c]) => {
+                model.add_max_equality(self.linear(c)?, [self.linear(a)?, self.linear(b)?]);
+            }
+            ("array_int_minimum", [m, xs]) => {
+                model.add_min_equality(self.linear(m)?, self.linear_array(xs)?);
+            }
+            ("array_int_maximum", [m, xs]) => {
+                model.add_max_equality(self.linear(m)?, self.linear_array(xs)?);
+            }
+            ("array_int_element" | "array_bool_element", [index, values, target]) => {
+                let values = self.constant_array(values)?;
+                let index = self.element_index(index, values.len())?;
+                model.add_element(index, &values, self.int_var(target)?);
+            }
+            ("array_var_int_element" | "array_var_bool_element", [index, vars, target]) => {
+                let vars = self
+                    .array(vars)?
+                    .iter()
+                    .map(|var| self.int_var(var))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let index = self.element_index(index, vars.len())?;
+                model.add_variable_element(index, &vars, self.int_var(target)?);
+            }
+            ("all_different_int" | "fzn_all_different_int", [xs]) => {
+                model.add_all_different(self.linear_array(xs)?);
+            }
+            ("bool2int", [b, i]) => {
+                model.add_linear_eq(self.linear(b)?, self.linear(i)?);
+            }
+            ("bool_not", [a, b]) => {
+                model.add_linear_eq(self.linear(a)? + self.linear(b)?, 1_i64);
+            }
+            ("bool_clause", [positives, negatives]) => {
+                let literals = self.clause(positives, negatives)?;
+                model.add_bool_or(&literals);
+            }
+            ("bool_clause_reif", [positives, negatives, r]) => {
+                let literals = self.clause(positives, negatives)?;
+                let r = self.literal(r)?;
+                self.add_reified_and(
+                    &literals.iter().map(|&literal| !literal).collect::<Vec<_>>(),
+                    !r,
+                );
+            }
+            ("bool_clause_imp", [positives, negatives, r]) => {
+                let literals = self.clause(positives, negatives)?;
+                let r = self.literal(r)?;
+                model.add_bool_or(&literals).only_enforce_if(&[r]);
+            }
+            ("array_bool_and", [literals, r]) => {
+                let literals = self.literal_array(literals)?;
+                let r = self.literal(r)?;
+                self.add_reified_and(&literals, r);
+            }
+            ("array_bool_and_imp", [literals, r]) => {
+                let literals = self.literal_array(literals)?;
+                let r = self.literal(r)?;
+                model.add_bool_and(&literals).only_enforce_if(&[r]);
+            }
+            ("array_bool_or_imp", [literals, r]) => {
+                let literals = self.literal_array(literals)?;
+                let r = self.literal(r)?;
+                model.add_bool_or(&literals).only_enforce_if(&[r]);
+            }
+            ("bool_and_imp", [a, b, r]) => {
+                let literals = [self.literal(a)?, self.literal(b)?];
+                let r = self.literal(r)?;
+                model.add_bool_and(&literals).only_enforce_if(&[r]);
+            }
+            ("bool_or_imp", [a, b, r]) => {
+                let literals = [self.literal(a)?, self.literal(b)?];
+                let r = self.literal(r)?;
+                model.add_bool_or(&literals).only_enforce_if(&[r]);
+            }
+            ("array_bool_or", [literals, r]) => {
+                let literals = self.literal_array(literals)?;
+                let r = self.literal(r)?;
+                self.add_reified_and(
+                    &literals.iter().map(|&literal| !literal).collect::<Vec<_>>(),
+                    !r,
+                );
+            }
+            ("bool_and", [a, b, r]) => {
+                let literals = [self.literal(a)?, self.literal(b)?];
+                let r = self.literal(r)?;
+                self.add_reified_and(&literals, r);
+            }
+            ("bool_or", [a, b, r]) => {
+                let literals = [!self.literal(a)?, !self.literal(b)?];
+                let r = self.literal(r)?;
+                self.add_reified_and(&literals, !r);
+            }
+            ("array_bool_xor", [literals]) => {
+                model.add_bool_xor(&self.literal_array(literals)?);
+            }
+            ("bool_xor", [a, b]) => {
+                model.add_bool_xor(&[self.literal(a)?, self.literal(b)?]);
+            }
+            ("bool_xor" | "bool_xor_reif", [a, b, r]) => {
+                // `r == a xor b` holds when `a xor b xor not(r)` is true.
+                let literals = [self.literal(a)?, self.literal(b)?, !self.literal(r)?];
+                model.add_bool_xor(&literals);
+            }
+            ("bool_xor_imp", [a, b, r]) => {
+                let literals = [self.literal(a)?, self.literal(b)?];
+                let r = self.literal(r)?;
+                model.add_bool_xor(&literals).only_enforce_if(&[r]);
+            }
+            _ => return Err("unsupported constraint".into()),
+        }
+        Ok(())
+    }
+
+    /// Returns the literals of `or(positives) \/ or(not(negatives))`, from the
+    /// arguments of the `bool_clause*` constraints.
+    fn clause(
+        &mut self,
+        positives: &Value<'model>,
+        negatives: &Value<'model>,
+    ) -> Result<Vec<BoolVar<'model>>, String> {
+        let mut literals = self.literal_array(positives)?;
+        for literal in self.literal_array(negatives)? {
+            literals.push(!literal);
+        }
+        Ok(literals)
+    }
+
+    /// Adds `r == and(literals)`.
+    fn add_reified_and(&self, literals: &[BoolVar<'model>], r: BoolVar<'model>) {
+        self.model.add_bool_and(literals).only_enforce_if(&[r]);
+        let mut clause: Vec<_> = literals.iter().map(|&literal| !literal).collect();
+        clause.push(r);
+        self.model.add_bool_or(&clause);
+    }
+
+    fn load_search_annotation(&mut self, annotation: &Expr) -> Result<(), String> {
+        let (name, args) = match annotation {
+            Expr::Call(name, args) => (name.as_str(), args.as_slice()),
+            _ => return Ok(()),
+        };
+        match (name, args) {
+            ("seq_search" | "warm_start_array", [Expr::Array(annotations)]) => {
+                for annotation in annotations {
+                    self.load_search_annotation(annotation)?;
+                }
+            }
+            (
+                "int_search" | "bool_search",
+                [vars, Expr::Ident(var_strategy), Expr::Ident(domain_strategy), ..],
+            ) => {
+                let vars = self.linear_array(&self.resolve(vars)?)?;
+                let var_strategy = match var_strategy.as_str() {
+                    "smallest" => VariableSelectionStrategy::ChooseLowestMin,
+                    "largest" => VariableSelectionStrategy::ChooseHighestMax,
+                    "first_fail" => VariableSelectionStrategy::ChooseMinDomainSize,
+                    "anti_first_fail" => VariableSelectionStrategy::ChooseMaxDomainSize,
+                    _ => VariableSelectionStrategy::ChooseFirst,
+                };
+                let domain_strategy = match domain_strategy.as_str() {
+                    "indomain_max" => DomainReductionStrategy::SelectMaxValue,
+                    "indomain_split" => DomainReductionStrategy::SelectLowerHalf,
+                    "indomain_reverse_split" => DomainReductionStrategy::SelectUpperHalf,
+                    "indomain_median" => DomainReductionStrategy::SelectMedianValue,
+                    _ => DomainReductionStrategy::SelectMinValue,
+                };
+                self.model
+                    .add_decision_strategy(vars, var_strategy, domain_strategy);
+            }
+            ("warm_start", [vars, values]) => {
+                let vars = self.resolve(vars)?;
+                let values = self.constant_array(&self.resolve(values)?)?;
+                for (var, value) in self.array(&vars)?.iter().zip(values) {
+                    match var {
+                        Value::IntVar(var) => self.model.add_hint(*var, value),
+                        Value::BoolVar(var) => self.model.add_bool_hint(*var, value != 0),
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+/// Writes the output variables of a solution, followed by `----------`.
+fn write_solution(
+    outputs: &[Output<'_>],
+    response: &CpSolverResponse,
+    writer: &mut impl Write,
+) -> io::Result<()> {
+    for output in outputs {
+        write!(writer, "{} = ", output.name)?;
+        match &output.dimensions {
+            None => write_value(&output.value, response, writer)?,
+            Some(dimensions) => {
+                write!(writer, "array{}d(", dimensions.len())?;
+                for dimension in dimensions {
+                    write!(writer, "{}..{}, ", dimension.start(), dimension.end())?;
+                }
+                write_value(&output.value, response, writer)?;
+                write!(writer, ")")?;
+            }
+        }
+        writeln!(writer, ";")?;
+    }
+    writeln!(writer, "----------")?;
+    writer.flush()
+}
+
+fn write_value(
+    value: &Value<'_>,
+    response: &CpSolverResponse,
+    writer: &mut impl Write,
+) -> io::Result<()> {
+    match value {
+        Value::Bool(value) => write!(writer, "{value}"),
+        Value::Int(value) => write!(writer, "{value}"),
+        Value::BoolVar(var) => write!(writer, "{}", response.boolean_value(var)),
+        Value::IntVar(var) => write!(writer, "{}", response.value(var)),
+        Value::Set(intervals) => {
+            let values: Vec<String> = intervals
+                .iter()
+                .flat_map(|interval| interval.clone())
+                .map(|value| value.to_string())
+                .collect();
+            write!(writer, "{{{}}}", values.join(", "))
+        }
+        Value::Array(values) => {
+            write!(writer, "[")?;
+            for (index, value) in values.iter().enumerate() {
+                if index > 0 {
+                    write!(writer, ", ")?;
+                }
+                write_value(value, response, writer)?;
+            }
+            write!(writer, "]")
+        }
+    }
+}
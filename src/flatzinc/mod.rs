@@ -0,0 +1,3 @@
+pub mod cp_model_fz_solver;
+pub mod model;
+pub mod parser;
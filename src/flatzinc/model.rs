@@ -0,0 +1,101 @@
+use std::ops::RangeInclusive;
+
+/// A FlatZinc model.
+///
+/// The declarations are kept in their order of appearance, as each of them can
+/// only refer to the ones declared before.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Model {
+    /// Parameter, variable and array declarations
+    pub declarations: Vec<Declaration>,
+    /// Constraint items
+    pub constraints: Vec<Constraint>,
+    /// Solve item
+    pub solve: SolveItem,
+}
+
+/// A named parameter, variable, or array of variables.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Declaration {
+    /// Name of the declaration
+    pub name: String,
+    /// Type of the declaration, or of its elements for an array
+    pub domain: Domain,
+    /// Whether the declaration is a decision variable (or an array of them),
+    /// or a parameter
+    pub is_var: bool,
+    /// Whether the declaration is an array
+    pub is_array: bool,
+    /// The assigned value, if any
+    pub value: Option<Expr>,
+    /// Annotations, such as `output_var` or `output_array`
+    pub annotations: Vec<Expr>,
+}
+
+/// The type of a declaration.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Domain {
+    /// `bool`
+    Bool,
+    /// `int`, or a set of integers given as sorted intervals
+    Int(Option<Vec<RangeInclusive<i64>>>),
+    /// `float`, or a float range
+    Float,
+    /// `set of int`, or a set of a given set of integers
+    IntSet,
+}
+
+/// A constraint item, e.g. `constraint int_le(x, y);`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Constraint {
+    /// Name of the constraint predicate
+    pub name: String,
+    /// Arguments of the constraint
+    pub args: Vec<Expr>,
+    /// Annotations, such as `defines_var`
+    pub annotations: Vec<Expr>,
+}
+
+/// The solve item.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SolveItem {
+    /// The kind of problem
+    pub goal: Goal,
+    /// Search annotations, such as `int_search`
+    pub annotations: Vec<Expr>,
+}
+
+/// The goal of the solve item.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum Goal {
+    /// `solve satisfy;`
+    #[default]
+    Satisfy,
+    /// `solve minimize <expr>;`
+    Minimize(Expr),
+    /// `solve maximize <expr>;`
+    Maximize(Expr),
+}
+
+/// A FlatZinc expression, used for values, arguments and annotations.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Expr {
+    /// `true` or `false`
+    Bool(bool),
+    /// An integer literal
+    Int(i64),
+    /// A float literal
+    Float(f64),
+    /// A set of integers, e.g. `1..3` or `{1, 3, 5}`, as sorted intervals
+    Set(Vec<RangeInclusive<i64>>),
+    /// A string literal
+    String(String),
+    /// A reference to a declaration, or an annotation without argument
+    Ident(String),
+    /// An element of an array declaration, e.g. `x[3]` (one-based)
+    ArrayAccess(String, i64),
+    /// An array literal, e.g. `[x, y, 3]`
+    Array(Vec<Expr>),
+    /// An annotation with arguments, e.g. `int_search(x, input_order, ...)`
+    Call(String, Vec<Expr>),
+}
@@ -0,0 +1,396 @@
+use std::{
+    fs,
+    io::{self, Read},
+    ops::RangeInclusive,
+    path::Path,
+};
+
+use super::model::{Constraint, Declaration, Domain, Expr, Goal, Model, SolveItem};
+
+/// Parses a FlatZinc model.
+///
+/// Predicate declarations are skipped, as FlatZinc solvers only need the
+/// built-in constraints and the ones of their own MiniZinc library.
+pub fn parse_flatzinc_string(input: &str) -> io::Result<Model> {
+    let tokens = tokenize(input).map_err(invalid_data)?;
+    Parser { tokens, pos: 0 }
+        .parse_model()
+        .map_err(invalid_data)
+}
+
+/// Parses a FlatZinc model from a reader.
+pub fn parse_flatzinc(mut reader: impl Read) -> io::Result<Model> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+    parse_flatzinc_string(&input)
+}
+
+/// Parses a FlatZinc file.
+pub fn parse_flatzinc_file(path: impl AsRef<Path>) -> io::Result<Model> {
+    parse_flatzinc_string(&fs::read_to_string(path)?)
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Ident(String),
+    Int(i64),
+    Float(f64),
+    String(String),
+    Punct(&'static str),
+}
+
+const PUNCTS: [&str; 12] = ["..", "::", ":", ";", ",", "(", ")", "[", "]", "{", "}", "="];
+
+/// Splits the input into tokens, each with its line number.
+fn tokenize(input: &str) -> Result<Vec<(Token, usize)>, String> {
+    let bytes = input.as_bytes();
+    let mut tokens = Vec::default();
+    let mut line = 1;
+    let mut pos = 0;
+
+    while pos < bytes.len() {
+        let c = bytes[pos];
+        if c == b'\n' {
+            line += 1;
+            pos += 1;
+        } else if c.is_ascii_whitespace() {
+            pos += 1;
+        } else if c == b'%' {
+            while pos < bytes.len() && bytes[pos] != b'\n' {
+                pos += 1;
+            }
+        } else if c == b'"' {
+            let start = pos + 1;
+            pos = start;
+            while pos < bytes.len() && bytes[pos] != b'"' {
+                if bytes[pos] == b'\\' {
+                    pos += 1;
+                }
+                pos += 1;
+            }
+            if pos >= bytes.len() {
+                return Err(format!("line {line}: unterminated string"));
+            }
+            tokens.push((Token::String(input[start..pos].to_string()), line));
+            pos += 1;
+        } else if c.is_ascii_alphabetic() || c == b'_' {
+            let start = pos;
+            while pos < bytes.len() && (bytes[pos].is_ascii_alphanumeric() || bytes[pos] == b'_') {
+                pos += 1;
+            }
+            tokens.push((Token::Ident(input[start..pos].to_string()), line));
+        } else if c.is_ascii_digit()
+            || (c == b'-' && bytes.get(pos + 1).is_some_and(u8::is_ascii_digit))
+        {
+            let start = pos;
+            pos += 1;
+            while pos < bytes.len() && bytes[pos].is_ascii_digit() {
+                pos += 1;
+            }
+            // A dot followed by a digit makes a float, while ".." is a range.
+            let mut is_float = false;
+            if bytes.get(pos) == Some(&b'.') && bytes.get(pos + 1).is_some_and(u8::is_ascii_digit) {
+                is_float = true;
+                pos += 1;
+                while pos < bytes.len() && bytes[pos].is_ascii_digit() {
+                    pos += 1;
+                }
+            }
+            if matches!(bytes.get(pos), Some(b'e' | b'E')) {
+                is_float = true;
+                pos += 1;
+                if matches!(bytes.get(pos), Some(b'+' | b'-')) {
+                    pos += 1;
+                }
+                while pos < bytes.len() && bytes[pos].is_ascii_digit() {
+                    pos += 1;
+                }
+            }
+            let text = &input[start..pos];
+            let token = if is_float {
+                text.parse().map(Token::Float).ok()
+            } else {
+                text.parse().map(Token::Int).ok()
+            };
+            match token {
+                Some(token) => tokens.push((token, line)),
+                None => return Err(format!("line {line}: invalid number {text:?}")),
+            }
+        } else {
+            match PUNCTS.iter().find(|punct| input[pos..].starts_with(*punct)) {
+                Some(punct) => {
+                    tokens.push((Token::Punct(punct), line));
+                    pos += punct.len();
+                }
+                None => {
+                    let c = input[pos..].chars().next().unwrap_or_default();
+                    return Err(format!("line {line}: unexpected character {c:?}"));
+                }
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+}
+
+impl Parser {
+    fn parse_model(&mut self) -> Result<Model, String> {
+        let mut model = Model::default();
+        let mut has_solve_item = false;
+
+        while let Some(token) = self.next() {
+            match token {
+                Token::Ident(keyword) if keyword == "predicate" => self.skip_item()?,
+                Token::Ident(keyword) if keyword == "constraint" => {
+                    let name = self.expect_ident()?;
+                    self.expect("(")?;
+                    let args = self.parse_list(")")?;
+                    let annotations = self.parse_annotations()?;
+                    self.expect(";")?;
+                    model.constraints.push(Constraint {
+                        name,
+                        args,
+                        annotations,
+                    });
+                }
+                Token::Ident(keyword) if keyword == "solve" => {
+                    let annotations = self.parse_annotations()?;
+                    let goal = match self.expect_ident()?.as_str() {
+                        "satisfy" => Goal::Satisfy,
+                        "minimize" => Goal::Minimize(self.parse_expr()?),
+                        "maximize" => Goal::Maximize(self.parse_expr()?),
+                        goal => return Err(self.error(format!("unknown solve goal {goal:?}"))),
+                    };
+                    self.expect(";")?;
+                    model.solve = SolveItem { goal, annotations };
+                    has_solve_item = true;
+                }
+                _ => {
+                    self.pos -= 1;
+                    let declaration = self.parse_declaration()?;
+                    model.declarations.push(declaration);
+                }
+            }
+        }
+
+        if has_solve_item {
+            Ok(model)
+        } else {
+            Err("missing solve item".into())
+        }
+    }
+
+    fn parse_declaration(&mut self) -> Result<Declaration, String> {
+        let is_array = self.eat_ident("array");
+        if is_array {
+            // The index set is always `1..n`, which is implied by the value.
+            self.expect("[")?;
+            if !self.eat_ident("int") {
+                self.parse_expr()?;
+            }
+            self.expect("]")?;
+            if !self.eat_ident("of") {
+                return Err(self.error("expected \"of\"".into()));
+            }
+        }
+        let is_var = self.eat_ident("var");
+        let domain = self.parse_domain()?;
+        self.expect(":")?;
+        let name = self.expect_ident()?;
+        let annotations = self.parse_annotations()?;
+        let value = if self.eat("=") {
+            Some(self.parse_expr()?)
+        } else {
+            None
+        };
+        self.expect(";")?;
+
+        if !is_var && value.is_none() {
+            return Err(self.error(format!("parameter {name:?} has no value")));
+        }
+        Ok(Declaration {
+            name,
+            domain,
+            is_var,
+            is_array,
+            value,
+            annotations,
+        })
+    }
+
+    fn parse_domain(&mut self) -> Result<Domain, String> {
+        if self.eat_ident("bool") {
+            return Ok(Domain::Bool);
+        }
+        if self.eat_ident("int") {
+            return Ok(Domain::Int(None));
+        }
+        if self.eat_ident("float") {
+            return Ok(Domain::Float);
+        }
+        if self.eat_ident("set") {
+            if !self.eat_ident("of") {
+                return Err(self.error("expected \"of\"".into()));
+            }
+            self.parse_domain()?;
+            return Ok(Domain::IntSet);
+        }
+        match self.parse_expr()? {
+            Expr::Set(intervals) => Ok(Domain::Int(Some(intervals))),
+            Expr::Float(_) => {
+                // Float ranges `a..b` are not further checked.
+                self.eat("..");
+                self.parse_expr()?;
+                Ok(Domain::Float)
+            }
+            _ => Err(self.error("expected a type".into())),
+        }
+    }
+
+    fn parse_annotations(&mut self) -> Result<Vec<Expr>, String> {
+        let mut annotations = Vec::default();
+        while self.eat("::") {
+            annotations.push(self.parse_expr()?);
+        }
+        Ok(annotations)
+    }
+
+    /// Parses a comma separated list of expressions up to the given closing
+    /// token.
+    fn parse_list(&mut self, close: &str) -> Result<Vec<Expr>, String> {
+        let mut exprs = Vec::default();
+        while !self.eat(close) {
+            exprs.push(self.parse_expr()?);
+            if !self.eat(",") {
+                self.expect(close)?;
+                break;
+            }
+        }
+        Ok(exprs)
+    }
+
+    fn parse_expr(&mut self) -> Result<Expr, String> {
+        match self.next() {
+            Some(Token::Int(value)) => {
+                if self.eat("..") {
+                    match self.next() {
+                        Some(Token::Int(end)) => Ok(Expr::Set(normalize(vec![value..=end]))),
+                        _ => Err(self.error("expected an integer".into())),
+                    }
+                } else {
+                    Ok(Expr::Int(value))
+                }
+            }
+            Some(Token::Float(value)) => Ok(Expr::Float(value)),
+            Some(Token::String(value)) => Ok(Expr::String(value)),
+            Some(Token::Punct("{")) => {
+                let mut values = Vec::default();
+                for expr in self.parse_list("}")? {
+                    match expr {
+                        Expr::Int(value) => values.push(value..=value),
+                        _ => return Err(self.error("expected an integer".into())),
+                    }
+                }
+                Ok(Expr::Set(normalize(values)))
+            }
+            Some(Token::Punct("[")) => self.parse_list("]").map(Expr::Array),
+            Some(Token::Ident(name)) => match name.as_str() {
+                "true" => Ok(Expr::Bool(true)),
+                "false" => Ok(Expr::Bool(false)),
+                _ if self.eat("(") => Ok(Expr::Call(name, self.parse_list(")")?)),
+                _ if self.eat("[") => match self.next() {
+                    Some(Token::Int(index)) => {
+                        self.expect("]")?;
+                        Ok(Expr::ArrayAccess(name, index))
+                    }
+                    _ => Err(self.error("expected an integer".into())),
+                },
+                _ => Ok(Expr::Ident(name)),
+            },
+            _ => Err(self.error("expected an expression".into())),
+        }
+    }
+
+    fn skip_item(&mut self) -> Result<(), String> {
+        while let Some(token) = self.next() {
+            if token == Token::Punct(";") {
+                return Ok(());
+            }
+        }
+        Err(self.error("expected \";\"".into()))
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).map(|(token, _)| token.clone());
+        self.pos += 1;
+        token
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(token, _)| token)
+    }
+
+    fn eat(&mut self, punct: &str) -> bool {
+        let found = matches!(self.peek(), Some(Token::Punct(p)) if *p == punct);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn eat_ident(&mut self, keyword: &str) -> bool {
+        let found = matches!(self.peek(), Some(Token::Ident(name)) if name == keyword);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn expect(&mut self, punct: &str) -> Result<(), String> {
+        if self.eat(punct) {
+            Ok(())
+        } else {
+            Err(self.error(format!("expected {punct:?}")))
+        }
+    }
+
+    fn expect_ident(&mut self) -> Result<String, String> {
+        match self.next() {
+            Some(Token::Ident(name)) => Ok(name),
+            _ => Err(self.error("expected an identifier".into())),
+        }
+    }
+
+    fn error(&self, message: String) -> String {
+        let tokens = &self.tokens;
+        match tokens.get(self.pos.min(tokens.len()).saturating_sub(1)) {
+            Some((_, line)) => format!("line {line}: {message}"),
+            None => message,
+        }
+    }
+}
+
+/// Sorts the given intervals, and merges the ones that overlap or touch.
+fn normalize(mut intervals: Vec<RangeInclusive<i64>>) -> Vec<RangeInclusive<i64>> {
+    intervals.retain(|interval| !interval.is_empty());
+    intervals.sort_by_key(|interval| *interval.start());
+
+    let mut merged: Vec<RangeInclusive<i64>> = Vec::with_capacity(intervals.len());
+    for interval in intervals {
+        match merged.last_mut() {
+            Some(last) if *interval.start() <= last.end().saturating_add(1) => {
+                *last = *last.start()..=*last.end().max(interval.end());
+            }
+            _ => merged.push(interval),
+        }
+    }
+    merged
+}
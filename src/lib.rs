@@ -5,6 +5,15 @@
 extern crate cpp;
 
 pub mod constraint_solver;
+#[cfg(feature = "solver-cp-sat")]
+pub mod flatzinc;
 pub mod graph;
 pub mod sat;
+pub mod util;
 pub mod utils;
+
+#[cfg(feature = "proto")]
+pub use or_tools_proto as proto;
+
+#[cfg(feature = "solver-cp-sat")]
+pub use or_tools_macros::cp_model;
@@ -1,12 +1,53 @@
+use std::{
+    cell::{RefCell, UnsafeCell},
+    ffi::c_int,
+    fmt, fs, io,
+    iter::Sum,
+    marker::PhantomData,
+    ops::{Add, AddAssign, ControlFlow, Deref, DerefMut, Mul, MulAssign, Neg, Not, Sub, SubAssign},
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    slice,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Arc,
+    },
+    thread,
+};
+
+use libffi::high::ClosureMut1;
+#[cfg(feature = "proto")]
+use or_tools_proto::{prost::Message, sat as proto};
+
+use crate::{util::sorted_interval_list::Domain, utils::string::CxxString};
+
+use super::sat_parameters::SatParameters;
+
 // IMPORT CXX LIBRARY
 cpp! {{
+    #include <algorithm>
+    #include <atomic>
+
+    #include <google/protobuf/text_format.h>
+
     #include "ortools/sat/cp_model.h"
+    #include "ortools/sat/cp_model_checker.h"
+    #include "ortools/sat/cp_model_solver.h"
+    #include "ortools/sat/model.h"
+    #include "ortools/sat/sat_parameters.pb.h"
+    #include "ortools/util/time_limit.h"
 }}
 
+/// The bounds of a variable domain must fall in `[-MAX_VARIABLE_BOUND,
+/// MAX_VARIABLE_BOUND]` for the model to be valid.
+const MAX_VARIABLE_BOUND: i64 = i64::MAX / 2;
+
 pub(crate) mod wrap {
+    use super::{DoubleLinearExpr, LinearExpr};
+
     #[doc(hidden)]
     pub trait Expr {
-        fn into_ptr(self) -> *mut ()
+        fn into_linear_expr(self) -> LinearExpr
         where
             Self: Sized;
     }
@@ -15,35 +56,4618 @@ pub(crate) mod wrap {
     where
         T: Expr,
     {
-        fn into_ptr(self) -> *mut () {
-            Box::leak(self) as *mut T as *mut ()
+        fn into_linear_expr(self) -> LinearExpr {
+            (*self).into_linear_expr()
+        }
+    }
+
+    #[doc(hidden)]
+    pub trait DoubleExpr {
+        fn into_double_linear_expr(self) -> DoubleLinearExpr
+        where
+            Self: Sized;
+    }
+
+    impl<T> DoubleExpr for Box<T>
+    where
+        T: DoubleExpr,
+    {
+        fn into_double_linear_expr(self) -> DoubleLinearExpr {
+            (*self).into_double_linear_expr()
+        }
+    }
+
+    #[doc(hidden)]
+    pub enum ObjectiveExpr {
+        Linear(LinearExpr),
+        Double(DoubleLinearExpr),
+    }
+
+    #[doc(hidden)]
+    pub trait Objective {
+        fn into_objective_expr(self) -> ObjectiveExpr
+        where
+            Self: Sized;
+    }
+
+    impl Objective for LinearExpr {
+        fn into_objective_expr(self) -> ObjectiveExpr {
+            ObjectiveExpr::Linear(self)
+        }
+    }
+
+    impl Objective for &LinearExpr {
+        fn into_objective_expr(self) -> ObjectiveExpr {
+            ObjectiveExpr::Linear(self.clone())
+        }
+    }
+
+    impl Objective for DoubleLinearExpr {
+        fn into_objective_expr(self) -> ObjectiveExpr {
+            ObjectiveExpr::Double(self)
+        }
+    }
+
+    impl Objective for &DoubleLinearExpr {
+        fn into_objective_expr(self) -> ObjectiveExpr {
+            ObjectiveExpr::Double(self.clone())
+        }
+    }
+
+    impl Objective for super::IntVar<'_> {
+        fn into_objective_expr(self) -> ObjectiveExpr {
+            ObjectiveExpr::Linear(self.into())
+        }
+    }
+
+    impl Objective for super::BoolVar<'_> {
+        fn into_objective_expr(self) -> ObjectiveExpr {
+            ObjectiveExpr::Linear(self.into())
         }
     }
 }
 
 cpp_class!(
-    /// An integer variable.
-    ///
-    /// This class wraps an IntegerVariableProto.
-    /// This can only be constructed via \c CpModelBuilder.NewIntVar().
-    ///
-    pub unsafe struct IntVar as "operations_research::sat::IntVar"
+    #[doc(hidden)]
+    unsafe struct CpModelBuilderInner as "operations_research::sat::CpModelBuilder"
 );
 
-impl self::wrap::Expr for IntVar {
-    fn into_ptr(mut self) -> *mut () {
-        &mut self as *mut IntVar as *mut ()
+/// Wrapper class around the cp_model proto.
+///
+/// This class provides two types of methods:
+///  - `new_xxx()` to create integer, boolean, or interval variables.
+///  - `add_xxx()` to create new constraints and add them to the model.
+///
+/// Variables are handles borrowed from the model, so they cannot outlive it.
+/// This is also why every method only takes a shared reference: creating a
+/// variable must not prevent adding constraints over it afterwards.
+pub struct CpModelBuilder {
+    /// Original model, mutated through shared references
+    inner: Box<UnsafeCell<CpModelBuilderInner>>,
+    /// Sum of the penalties of the soft constraints, added to any objective
+    penalties: RefCell<LinearExpr>,
+}
+
+impl Default for CpModelBuilder {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
-cpp_class!(
-    /// A constraint.
+impl CpModelBuilder {
+    /// Creates an empty model.
+    pub fn new() -> Self {
+        Self {
+            inner: unsafe {
+                cpp!([
+                ] -> Box<UnsafeCell<CpModelBuilderInner>> as "operations_research::sat::CpModelBuilder*"
+                    {
+                        return new operations_research::sat::CpModelBuilder();
+                    }
+                )
+            },
+            penalties: RefCell::default(),
+        }
+    }
+
+    /// Creates a model from a serialized CpModelProto, as produced by
+    /// `export_to_bytes()`.
     ///
-    /// This class enables you to modify the constraint that was previously added to
-    /// the model.
+    /// Returns `None` if the bytes could not be parsed.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let model = Self::new();
+        let inner = model.inner.get();
+
+        let bytes_ptr = bytes.as_ptr();
+        let bytes_len = bytes.len();
+
+        let parsed = unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                bytes_ptr as "const void*",
+                bytes_len as "size_t"
+            ] -> bool as "bool"
+                {
+                    operations_research::sat::CpModelProto proto;
+                    if (!proto.ParseFromArray(bytes_ptr, static_cast<int>(bytes_len))) {
+                        return false;
+                    }
+                    inner->CopyFrom(proto);
+                    return true;
+                }
+            )
+        };
+        parsed.then_some(model)
+    }
+
+    /// Creates a model from a CpModelProto in the protobuf text format, as
+    /// produced by `export_to_text()`.
     ///
-    /// The constraint must be built using the different `CpModelBuilder::AddXXX`
-    /// methods.
+    /// Returns `None` if the text could not be parsed.
+    pub fn from_text(text: &str) -> Option<Self> {
+        let model = Self::new();
+        let inner = model.inner.get();
+
+        let text_ptr = text.as_ptr();
+        let text_len = text.len();
+
+        let parsed = unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                text_ptr as "const char*",
+                text_len as "size_t"
+            ] -> bool as "bool"
+                {
+                    operations_research::sat::CpModelProto proto;
+                    if (!google::protobuf::TextFormat::ParseFromString(
+                        std::string(text_ptr, text_len),
+                        &proto
+                    )) {
+                        return false;
+                    }
+                    inner->CopyFrom(proto);
+                    return true;
+                }
+            )
+        };
+        parsed.then_some(model)
+    }
+
+    /// Loads a model written by `export_to_file()`.
     ///
-    pub unsafe struct Constraint as "operations_research::sat::Constraint"
-);
+    /// Like `export_to_file()`, the text format is used if the path ends with
+    /// ".txt", and the binary format otherwise.
+    pub fn from_file(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        if is_text_proto_file(path) {
+            Self::from_text_file(path)
+        } else {
+            let bytes = fs::read(path)?;
+            Self::from_bytes(&bytes).ok_or_else(|| invalid_model_file(path))
+        }
+    }
+
+    /// Loads a model from a file in the protobuf text format, regardless of its
+    /// extension.
+    pub fn from_text_file(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path)?;
+        Self::from_text(&text).ok_or_else(|| invalid_model_file(path))
+    }
+
+    /// Returns the serialized CpModelProto of the model.
+    pub fn export_to_bytes(&self) -> Vec<u8> {
+        let inner = self.inner.get();
+
+        unsafe {
+            cpp!([
+                inner as "const operations_research::sat::CpModelBuilder*"
+            ] -> CxxString as "std::unique_ptr<std::string>"
+                {
+                    return std::make_unique<std::string>(inner->Proto().SerializeAsString());
+                }
+            )
+        }
+        .as_bytes()
+        .to_vec()
+    }
+
+    /// Creates a model from the pure-Rust CpModelProto, as produced by
+    /// `export_to_proto()`.
+    ///
+    /// Returns `None` if the native library rejects the message.
+    #[cfg(feature = "proto")]
+    pub fn from_proto(proto: &proto::CpModelProto) -> Option<Self> {
+        Self::from_bytes(&proto.encode_to_vec())
+    }
+
+    /// Returns the CpModelProto of the model as a pure-Rust message, which can
+    /// be inspected and sent to processes that do not link the native library.
+    #[cfg(feature = "proto")]
+    pub fn export_to_proto(&self) -> proto::CpModelProto {
+        proto::CpModelProto::decode(self.export_to_bytes().as_slice())
+            .expect("the native library should serialize a valid CpModelProto")
+    }
+
+    /// Returns the CpModelProto of the model in the protobuf text format.
+    pub fn export_to_text(&self) -> String {
+        let inner = self.inner.get();
+
+        unsafe {
+            cpp!([
+                inner as "const operations_research::sat::CpModelBuilder*"
+            ] -> CxxString as "std::unique_ptr<std::string>"
+                {
+                    std::string text;
+                    google::protobuf::TextFormat::PrintToString(inner->Proto(), &text);
+                    return std::make_unique<std::string>(std::move(text));
+                }
+            )
+        }
+        .into()
+    }
+
+    /// Export the model to file.
+    ///
+    /// The model is written in the text format if the path ends with ".txt",
+    /// and in the binary format otherwise.
+    pub fn export_to_file(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        if is_text_proto_file(path) {
+            fs::write(path, self.export_to_text())
+        } else {
+            fs::write(path, self.export_to_bytes())
+        }
+    }
+
+    /// Creates an integer variable with the given domain.
+    ///
+    /// The domain can be a single range like `0..=10`, or any `Domain`,
+    /// including ones with holes.
+    pub fn new_int_var(&self, domain: impl Into<Domain>) -> IntVar<'_> {
+        let inner = self.inner.get();
+
+        let domain = domain.into();
+        let domain = &domain;
+
+        IntVar::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                domain as "const operations_research::Domain*"
+            ] -> IntVarInner as "operations_research::sat::IntVar"
+                {
+                    return inner->NewIntVar(*domain);
+                }
+            )
+        })
+    }
+
+    /// Creates a Boolean variable.
+    pub fn new_bool_var(&self) -> BoolVar<'_> {
+        let inner = self.inner.get();
+
+        BoolVar::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*"
+            ] -> BoolVarInner as "operations_research::sat::BoolVar"
+                {
+                    return inner->NewBoolVar();
+                }
+            )
+        })
+    }
+
+    /// Creates a constant variable. This is a shortcut for
+    /// `new_int_var(value..=value)`, but it will return the same variable if
+    /// used twice with the same constant.
+    pub fn new_constant(&self, value: i64) -> IntVar<'_> {
+        let inner = self.inner.get();
+
+        IntVar::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                value as "int64_t"
+            ] -> IntVarInner as "operations_research::sat::IntVar"
+                {
+                    return inner->NewConstant(value);
+                }
+            )
+        })
+    }
+
+    /// Creates an interval variable from 3 affine expressions.
+    pub fn new_interval_var(
+        &self,
+        start: impl Into<LinearExpr>,
+        size: impl Into<LinearExpr>,
+        end: impl Into<LinearExpr>,
+    ) -> IntervalVar<'_> {
+        let inner = self.inner.get();
+
+        let start = start.into();
+        let start = &start;
+        let size = size.into();
+        let size = &size;
+        let end = end.into();
+        let end = &end;
+
+        IntervalVar::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                start as "const operations_research::sat::LinearExpr*",
+                size as "const operations_research::sat::LinearExpr*",
+                end as "const operations_research::sat::LinearExpr*"
+            ] -> IntervalVarInner as "operations_research::sat::IntervalVar"
+                {
+                    return inner->NewIntervalVar(*start, *size, *end);
+                }
+            )
+        })
+    }
+
+    /// Creates an interval variable with a fixed size.
+    pub fn new_fixed_size_interval_var(
+        &self,
+        start: impl Into<LinearExpr>,
+        size: i64,
+    ) -> IntervalVar<'_> {
+        let inner = self.inner.get();
+
+        let start = start.into();
+        let start = &start;
+
+        IntervalVar::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                start as "const operations_research::sat::LinearExpr*",
+                size as "int64_t"
+            ] -> IntervalVarInner as "operations_research::sat::IntervalVar"
+                {
+                    return inner->NewFixedSizeIntervalVar(*start, size);
+                }
+            )
+        })
+    }
+
+    /// Creates an optional interval variable from 3 affine expressions and a
+    /// Boolean variable.
+    pub fn new_optional_interval_var(
+        &self,
+        start: impl Into<LinearExpr>,
+        size: impl Into<LinearExpr>,
+        end: impl Into<LinearExpr>,
+        presence: BoolVar<'_>,
+    ) -> IntervalVar<'_> {
+        let inner = self.inner.get();
+
+        let start = start.into();
+        let start = &start;
+        let size = size.into();
+        let size = &size;
+        let end = end.into();
+        let end = &end;
+        let presence = &presence.inner;
+
+        IntervalVar::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                start as "const operations_research::sat::LinearExpr*",
+                size as "const operations_research::sat::LinearExpr*",
+                end as "const operations_research::sat::LinearExpr*",
+                presence as "const operations_research::sat::BoolVar*"
+            ] -> IntervalVarInner as "operations_research::sat::IntervalVar"
+                {
+                    return inner->NewOptionalIntervalVar(*start, *size, *end, *presence);
+                }
+            )
+        })
+    }
+
+    /// Creates an optional interval variable with a fixed size.
+    pub fn new_optional_fixed_size_interval_var(
+        &self,
+        start: impl Into<LinearExpr>,
+        size: i64,
+        presence: BoolVar<'_>,
+    ) -> IntervalVar<'_> {
+        let inner = self.inner.get();
+
+        let start = start.into();
+        let start = &start;
+        let presence = &presence.inner;
+
+        IntervalVar::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                start as "const operations_research::sat::LinearExpr*",
+                size as "int64_t",
+                presence as "const operations_research::sat::BoolVar*"
+            ] -> IntervalVarInner as "operations_research::sat::IntervalVar"
+                {
+                    return inner->NewOptionalFixedSizeIntervalVar(*start, size, *presence);
+                }
+            )
+        })
+    }
+
+    /// Adds the constraint that at least one of the literals must be true.
+    pub fn add_bool_or(&self, literals: &[BoolVar<'_>]) -> Constraint<'_> {
+        let inner = self.inner.get();
+
+        let literals_ptr = literals.as_ptr();
+        let literals_len = literals.len();
+
+        Constraint::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                literals_ptr as "const operations_research::sat::BoolVar*",
+                literals_len as "size_t"
+            ] -> ConstraintInner as "operations_research::sat::Constraint"
+                {
+                    return inner->AddBoolOr(
+                        absl::Span<const operations_research::sat::BoolVar>(literals_ptr, literals_len)
+                    );
+                }
+            )
+        })
+    }
+
+    /// Adds the constraint that all literals must be true.
+    pub fn add_bool_and(&self, literals: &[BoolVar<'_>]) -> Constraint<'_> {
+        let inner = self.inner.get();
+
+        let literals_ptr = literals.as_ptr();
+        let literals_len = literals.len();
+
+        Constraint::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                literals_ptr as "const operations_research::sat::BoolVar*",
+                literals_len as "size_t"
+            ] -> ConstraintInner as "operations_research::sat::Constraint"
+                {
+                    return inner->AddBoolAnd(
+                        absl::Span<const operations_research::sat::BoolVar>(literals_ptr, literals_len)
+                    );
+                }
+            )
+        })
+    }
+
+    /// Adds the constraint that an odd number of literals is true.
+    pub fn add_bool_xor(&self, literals: &[BoolVar<'_>]) -> Constraint<'_> {
+        let inner = self.inner.get();
+
+        let literals_ptr = literals.as_ptr();
+        let literals_len = literals.len();
+
+        Constraint::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                literals_ptr as "const operations_research::sat::BoolVar*",
+                literals_len as "size_t"
+            ] -> ConstraintInner as "operations_research::sat::Constraint"
+                {
+                    return inner->AddBoolXor(
+                        absl::Span<const operations_research::sat::BoolVar>(literals_ptr, literals_len)
+                    );
+                }
+            )
+        })
+    }
+
+    /// Adds the constraint that at most one of the literals must be true.
+    pub fn add_at_most_one(&self, literals: &[BoolVar<'_>]) -> Constraint<'_> {
+        let inner = self.inner.get();
+
+        let literals_ptr = literals.as_ptr();
+        let literals_len = literals.len();
+
+        Constraint::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                literals_ptr as "const operations_research::sat::BoolVar*",
+                literals_len as "size_t"
+            ] -> ConstraintInner as "operations_research::sat::Constraint"
+                {
+                    return inner->AddAtMostOne(
+                        absl::Span<const operations_research::sat::BoolVar>(literals_ptr, literals_len)
+                    );
+                }
+            )
+        })
+    }
+
+    /// Adds the constraint that exactly one of the literals must be true.
+    pub fn add_exactly_one(&self, literals: &[BoolVar<'_>]) -> Constraint<'_> {
+        let inner = self.inner.get();
+
+        let literals_ptr = literals.as_ptr();
+        let literals_len = literals.len();
+
+        Constraint::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                literals_ptr as "const operations_research::sat::BoolVar*",
+                literals_len as "size_t"
+            ] -> ConstraintInner as "operations_research::sat::Constraint"
+                {
+                    return inner->AddExactlyOne(
+                        absl::Span<const operations_research::sat::BoolVar>(literals_ptr, literals_len)
+                    );
+                }
+            )
+        })
+    }
+
+    /// Adds `a => b`.
+    pub fn add_implication(&self, a: BoolVar<'_>, b: BoolVar<'_>) -> Constraint<'_> {
+        let inner = self.inner.get();
+
+        let a = &a.inner;
+        let b = &b.inner;
+
+        Constraint::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                a as "const operations_research::sat::BoolVar*",
+                b as "const operations_research::sat::BoolVar*"
+            ] -> ConstraintInner as "operations_research::sat::Constraint"
+                {
+                    return inner->AddImplication(*a, *b);
+                }
+            )
+        })
+    }
+
+    /// Adds `left == right`.
+    pub fn add_linear_eq(
+        &self,
+        left: impl Into<LinearExpr>,
+        right: impl Into<LinearExpr>,
+    ) -> Constraint<'_> {
+        let inner = self.inner.get();
+
+        let left = left.into();
+        let left = &left;
+        let right = right.into();
+        let right = &right;
+
+        Constraint::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                left as "const operations_research::sat::LinearExpr*",
+                right as "const operations_research::sat::LinearExpr*"
+            ] -> ConstraintInner as "operations_research::sat::Constraint"
+                {
+                    return inner->AddEquality(*left, *right);
+                }
+            )
+        })
+    }
+
+    /// Adds `left != right`.
+    pub fn add_linear_ne(
+        &self,
+        left: impl Into<LinearExpr>,
+        right: impl Into<LinearExpr>,
+    ) -> Constraint<'_> {
+        let inner = self.inner.get();
+
+        let left = left.into();
+        let left = &left;
+        let right = right.into();
+        let right = &right;
+
+        Constraint::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                left as "const operations_research::sat::LinearExpr*",
+                right as "const operations_research::sat::LinearExpr*"
+            ] -> ConstraintInner as "operations_research::sat::Constraint"
+                {
+                    return inner->AddNotEqual(*left, *right);
+                }
+            )
+        })
+    }
+
+    /// Adds `left <= right`.
+    pub fn add_linear_le(
+        &self,
+        left: impl Into<LinearExpr>,
+        right: impl Into<LinearExpr>,
+    ) -> Constraint<'_> {
+        let inner = self.inner.get();
+
+        let left = left.into();
+        let left = &left;
+        let right = right.into();
+        let right = &right;
+
+        Constraint::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                left as "const operations_research::sat::LinearExpr*",
+                right as "const operations_research::sat::LinearExpr*"
+            ] -> ConstraintInner as "operations_research::sat::Constraint"
+                {
+                    return inner->AddLessOrEqual(*left, *right);
+                }
+            )
+        })
+    }
+
+    /// Adds `left >= right`.
+    pub fn add_linear_ge(
+        &self,
+        left: impl Into<LinearExpr>,
+        right: impl Into<LinearExpr>,
+    ) -> Constraint<'_> {
+        let inner = self.inner.get();
+
+        let left = left.into();
+        let left = &left;
+        let right = right.into();
+        let right = &right;
+
+        Constraint::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                left as "const operations_research::sat::LinearExpr*",
+                right as "const operations_research::sat::LinearExpr*"
+            ] -> ConstraintInner as "operations_research::sat::Constraint"
+                {
+                    return inner->AddGreaterOrEqual(*left, *right);
+                }
+            )
+        })
+    }
+
+    /// Adds `left < right`.
+    pub fn add_linear_lt(
+        &self,
+        left: impl Into<LinearExpr>,
+        right: impl Into<LinearExpr>,
+    ) -> Constraint<'_> {
+        let inner = self.inner.get();
+
+        let left = left.into();
+        let left = &left;
+        let right = right.into();
+        let right = &right;
+
+        Constraint::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                left as "const operations_research::sat::LinearExpr*",
+                right as "const operations_research::sat::LinearExpr*"
+            ] -> ConstraintInner as "operations_research::sat::Constraint"
+                {
+                    return inner->AddLessThan(*left, *right);
+                }
+            )
+        })
+    }
+
+    /// Adds `left > right`.
+    pub fn add_linear_gt(
+        &self,
+        left: impl Into<LinearExpr>,
+        right: impl Into<LinearExpr>,
+    ) -> Constraint<'_> {
+        let inner = self.inner.get();
+
+        let left = left.into();
+        let left = &left;
+        let right = right.into();
+        let right = &right;
+
+        Constraint::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                left as "const operations_research::sat::LinearExpr*",
+                right as "const operations_research::sat::LinearExpr*"
+            ] -> ConstraintInner as "operations_research::sat::Constraint"
+                {
+                    return inner->AddGreaterThan(*left, *right);
+                }
+            )
+        })
+    }
+
+    /// Adds `expr` in `domain`.
+    pub fn add_linear_in_domain(
+        &self,
+        expr: impl Into<LinearExpr>,
+        domain: impl Into<Domain>,
+    ) -> Constraint<'_> {
+        let inner = self.inner.get();
+
+        let expr = expr.into();
+        let expr = &expr;
+        let domain = domain.into();
+        let domain = &domain;
+
+        Constraint::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                expr as "const operations_research::sat::LinearExpr*",
+                domain as "const operations_research::Domain*"
+            ] -> ConstraintInner as "operations_research::sat::Constraint"
+                {
+                    return inner->AddLinearConstraint(*expr, *domain);
+                }
+            )
+        })
+    }
+
+    /// This constraint forces all expressions to have different values.
+    pub fn add_all_different<T>(&self, exprs: impl IntoIterator<Item = T>) -> Constraint<'_>
+    where
+        T: Into<LinearExpr>,
+    {
+        let inner = self.inner.get();
+
+        let exprs: Vec<LinearExpr> = exprs.into_iter().map(Into::into).collect();
+        let exprs_ptr = exprs.as_ptr();
+        let exprs_len = exprs.len();
+
+        Constraint::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                exprs_ptr as "const operations_research::sat::LinearExpr*",
+                exprs_len as "size_t"
+            ] -> ConstraintInner as "operations_research::sat::Constraint"
+                {
+                    return inner->AddAllDifferent(
+                        absl::Span<const operations_research::sat::LinearExpr>(exprs_ptr, exprs_len)
+                    );
+                }
+            )
+        })
+    }
+
+    /// Adds the element constraint: `values[index] == target`.
+    pub fn add_element(
+        &self,
+        index: IntVar<'_>,
+        values: &[i64],
+        target: IntVar<'_>,
+    ) -> Constraint<'_> {
+        let inner = self.inner.get();
+
+        let index = &index.inner;
+        let values_ptr = values.as_ptr();
+        let values_len = values.len();
+        let target = &target.inner;
+
+        Constraint::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                index as "const operations_research::sat::IntVar*",
+                values_ptr as "const int64_t*",
+                values_len as "size_t",
+                target as "const operations_research::sat::IntVar*"
+            ] -> ConstraintInner as "operations_research::sat::Constraint"
+                {
+                    return inner->AddElement(
+                        *index,
+                        absl::Span<const int64_t>(values_ptr, values_len),
+                        *target
+                    );
+                }
+            )
+        })
+    }
+
+    /// Adds the element constraint: `variables[index] == target`.
+    pub fn add_variable_element(
+        &self,
+        index: IntVar<'_>,
+        variables: &[IntVar<'_>],
+        target: IntVar<'_>,
+    ) -> Constraint<'_> {
+        let inner = self.inner.get();
+
+        let index = &index.inner;
+        let variables_ptr = variables.as_ptr();
+        let variables_len = variables.len();
+        let target = &target.inner;
+
+        Constraint::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                index as "const operations_research::sat::IntVar*",
+                variables_ptr as "const operations_research::sat::IntVar*",
+                variables_len as "size_t",
+                target as "const operations_research::sat::IntVar*"
+            ] -> ConstraintInner as "operations_research::sat::Constraint"
+                {
+                    return inner->AddVariableElement(
+                        *index,
+                        absl::Span<const operations_research::sat::IntVar>(variables_ptr, variables_len),
+                        *target
+                    );
+                }
+            )
+        })
+    }
+
+    /// Adds a circuit constraint.
+    ///
+    /// The circuit constraint is defined on a graph where the arc presence is
+    /// controlled by literals. That is the arc is part of the circuit of its
+    /// corresponding literal is assigned to true.
+    ///
+    /// For now, we ignore node indices with no incident arc. All the other nodes
+    /// must have exactly one incoming and one outgoing selected arc (i.e. literal at
+    /// true). All the selected arcs that are not self-loops must form a single
+    /// circuit.
+    ///
+    /// It returns a circuit constraint that allows adding arcs incrementally after
+    /// construction.
+    pub fn add_circuit(&self) -> CircuitConstraint<'_> {
+        let inner = self.inner.get();
+
+        CircuitConstraint::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*"
+            ] -> CircuitConstraintInner as "operations_research::sat::CircuitConstraint"
+                {
+                    return inner->AddCircuitConstraint();
+                }
+            )
+        })
+    }
+
+    /// Adds a multiple circuit constraint, aka the "VRP" (Vehicle Routing Problem)
+    /// constraint.
+    ///
+    /// The direct graph where arc #i (from tails\[i\] to head\[i\]) is present iff
+    /// literals\[i\] is true must satisfy this set of properties:
+    /// - #incoming arcs == 1 except for node 0.
+    /// - #outgoing arcs == 1 except for node 0.
+    /// - for node zero, #incoming arcs should be the same as #outgoing arcs.
+    /// - There are no duplicate arcs.
+    /// - Self-arcs are allowed except for node 0.
+    /// - There is no cycle in this graph, except through node 0.
+    ///
+    /// Note: Currently this constraint expects all the nodes in [0, num_nodes) to
+    /// have at least one incident arc. The model will be considered invalid if it
+    /// is not the case. You can add self-arc fixed to one to ignore some nodes if
+    /// needed.
+    pub fn add_multiple_circuit(&self) -> MultipleCircuitConstraint<'_> {
+        let inner = self.inner.get();
+
+        MultipleCircuitConstraint::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*"
+            ] -> MultipleCircuitConstraintInner as "operations_research::sat::MultipleCircuitConstraint"
+                {
+                    return inner->AddMultipleCircuitConstraint();
+                }
+            )
+        })
+    }
+
+    /// Adds an allowed assignments constraint.
+    ///
+    /// An AllowedAssignments constraint is a constraint on an array of variables
+    /// that forces, when all variables are fixed to a single value, that the
+    /// corresponding list of values is equal to one of the tuples added to the
+    /// constraint.
+    ///
+    /// It returns a table constraint that allows adding tuples incrementally after
+    /// construction.
+    pub fn add_allowed_assignments(&self, vars: &[IntVar<'_>]) -> TableConstraint<'_> {
+        let inner = self.inner.get();
+
+        let vars_ptr = vars.as_ptr();
+        let vars_len = vars.len();
+
+        TableConstraint::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                vars_ptr as "const operations_research::sat::IntVar*",
+                vars_len as "size_t"
+            ] -> TableConstraintInner as "operations_research::sat::TableConstraint"
+                {
+                    return inner->AddAllowedAssignments(
+                        absl::Span<const operations_research::sat::IntVar>(vars_ptr, vars_len)
+                    );
+                }
+            )
+        })
+    }
+
+    /// Adds an forbidden assignments constraint.
+    ///
+    /// A ForbiddenAssignments constraint is a constraint on an array of variables
+    /// where the list of impossible combinations is provided in the tuples added to
+    /// the constraint.
+    ///
+    /// It returns a table constraint that allows adding tuples incrementally after
+    /// construction.
+    pub fn add_forbidden_assignments(&self, vars: &[IntVar<'_>]) -> TableConstraint<'_> {
+        let inner = self.inner.get();
+
+        let vars_ptr = vars.as_ptr();
+        let vars_len = vars.len();
+
+        TableConstraint::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                vars_ptr as "const operations_research::sat::IntVar*",
+                vars_len as "size_t"
+            ] -> TableConstraintInner as "operations_research::sat::TableConstraint"
+                {
+                    return inner->AddForbiddenAssignments(
+                        absl::Span<const operations_research::sat::IntVar>(vars_ptr, vars_len)
+                    );
+                }
+            )
+        })
+    }
+
+    /// An inverse constraint.
+    ///
+    /// It enforces that if `variables[i]` is assigned a value
+    /// `j`, then `inverse_variables[j]` is assigned a value `i`. And vice versa.
+    pub fn add_inverse(
+        &self,
+        variables: &[IntVar<'_>],
+        inverse_variables: &[IntVar<'_>],
+    ) -> Constraint<'_> {
+        let inner = self.inner.get();
+
+        let variables_ptr = variables.as_ptr();
+        let variables_len = variables.len();
+        let inverse_variables_ptr = inverse_variables.as_ptr();
+        let inverse_variables_len = inverse_variables.len();
+
+        Constraint::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                variables_ptr as "const operations_research::sat::IntVar*",
+                variables_len as "size_t",
+                inverse_variables_ptr as "const operations_research::sat::IntVar*",
+                inverse_variables_len as "size_t"
+            ] -> ConstraintInner as "operations_research::sat::Constraint"
+                {
+                    return inner->AddInverseConstraint(
+                        absl::Span<const operations_research::sat::IntVar>(variables_ptr, variables_len),
+                        absl::Span<const operations_research::sat::IntVar>(inverse_variables_ptr, inverse_variables_len)
+                    );
+                }
+            )
+        })
+    }
+
+    /// Adds a reservoir constraint with optional refill/emptying events.
+    ///
+    /// Maintain a reservoir level within bounds. The water level starts at 0, and
+    /// at any time, it must be within [min_level, max_level].
+    ///
+    /// Given an event (time, level_change, active), if active is true, and if time
+    /// is assigned a value t, then the level of the reservoir changes by
+    /// level_change (which is constant) at time t. Therefore, at any time t:
+    ///
+    /// sum(level_changes\[i\] * actives\[i\] if times\[i\] <= t) in [min_level, max_level]
+    ///
+    /// Note that min level must be <= 0, and the max level must be >= 0.
+    /// Please use fixed level_changes to simulate an initial state.
+    ///
+    /// It returns a ReservoirConstraint that allows adding optional and non
+    /// optional events incrementally after construction.
+    pub fn add_reservoir_constraint(
+        &self,
+        min_level: i64,
+        max_level: i64,
+    ) -> ReservoirConstraint<'_> {
+        let inner = self.inner.get();
+
+        ReservoirConstraint::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                min_level as "int64_t",
+                max_level as "int64_t"
+            ] -> ReservoirConstraintInner as "operations_research::sat::ReservoirConstraint"
+                {
+                    return inner->AddReservoirConstraint(min_level, max_level);
+                }
+            )
+        })
+    }
+
+    /// An automaton constraint.
+    ///
+    /// An automaton constraint takes a list of variables (of size n), an initial
+    /// state, a set of final states, and a set of transitions. A transition is a
+    /// triplet (`tail`, `head`, `label`), where `tail` and `head` are states,
+    /// and `label` is the label of an arc from `head` to `tail`,
+    /// corresponding to the value of one variable in the list of variables.
+    ///
+    /// This automaton will be unrolled into a flow with n + 1 phases. Each phase
+    /// contains the possible states of the automaton. The first state contains the
+    /// initial state. The last phase contains the final states.
+    ///
+    /// Between two consecutive phases i and i + 1, the automaton creates a set of
+    /// arcs. For each transition (tail, head, label), it will add an arc from
+    /// the state `tail` of phase i and the state `head` of phase i + 1. This arc
+    /// labeled by the value `label` of the variables `variables[i]`. That is,
+    /// this arc can only be selected if `variables[i]` is assigned the value
+    /// `label`. A feasible solution of this constraint is an assignment of
+    /// variables such that, starting from the initial state in phase 0, there is a
+    /// path labeled by the values of the variables that ends in one of the final
+    /// states in the final phase.
+    ///
+    /// It returns an AutomatonConstraint that allows adding transition
+    /// incrementally after construction.
+    pub fn add_automaton(
+        &self,
+        transition_variables: &[IntVar<'_>],
+        starting_state: c_int,
+        final_states: &[c_int],
+    ) -> AutomatonConstraint<'_> {
+        let inner = self.inner.get();
+
+        let transition_variables_ptr = transition_variables.as_ptr();
+        let transition_variables_len = transition_variables.len();
+        let final_states_ptr = final_states.as_ptr();
+        let final_states_len = final_states.len();
+
+        AutomatonConstraint::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                transition_variables_ptr as "const operations_research::sat::IntVar*",
+                transition_variables_len as "size_t",
+                starting_state as "int",
+                final_states_ptr as "const int*",
+                final_states_len as "size_t"
+            ] -> AutomatonConstraintInner as "operations_research::sat::AutomatonConstraint"
+                {
+                    return inner->AddAutomaton(
+                        absl::Span<const operations_research::sat::IntVar>(transition_variables_ptr, transition_variables_len),
+                        starting_state,
+                        absl::Span<const int>(final_states_ptr, final_states_len)
+                    );
+                }
+            )
+        })
+    }
+
+    /// Adds target == min(exprs).
+    pub fn add_min_equality<T>(
+        &self,
+        target: impl Into<LinearExpr>,
+        exprs: impl IntoIterator<Item = T>,
+    ) -> Constraint<'_>
+    where
+        T: Into<LinearExpr>,
+    {
+        let inner = self.inner.get();
+
+        let target = target.into();
+        let target = &target;
+        let exprs: Vec<LinearExpr> = exprs.into_iter().map(Into::into).collect();
+        let exprs_ptr = exprs.as_ptr();
+        let exprs_len = exprs.len();
+
+        Constraint::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                target as "const operations_research::sat::LinearExpr*",
+                exprs_ptr as "const operations_research::sat::LinearExpr*",
+                exprs_len as "size_t"
+            ] -> ConstraintInner as "operations_research::sat::Constraint"
+                {
+                    return inner->AddMinEquality(
+                        *target,
+                        absl::Span<const operations_research::sat::LinearExpr>(exprs_ptr, exprs_len)
+                    );
+                }
+            )
+        })
+    }
+
+    /// Adds target == max(exprs).
+    pub fn add_max_equality<T>(
+        &self,
+        target: impl Into<LinearExpr>,
+        exprs: impl IntoIterator<Item = T>,
+    ) -> Constraint<'_>
+    where
+        T: Into<LinearExpr>,
+    {
+        let inner = self.inner.get();
+
+        let target = target.into();
+        let target = &target;
+        let exprs: Vec<LinearExpr> = exprs.into_iter().map(Into::into).collect();
+        let exprs_ptr = exprs.as_ptr();
+        let exprs_len = exprs.len();
+
+        Constraint::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                target as "const operations_research::sat::LinearExpr*",
+                exprs_ptr as "const operations_research::sat::LinearExpr*",
+                exprs_len as "size_t"
+            ] -> ConstraintInner as "operations_research::sat::Constraint"
+                {
+                    return inner->AddMaxEquality(
+                        *target,
+                        absl::Span<const operations_research::sat::LinearExpr>(exprs_ptr, exprs_len)
+                    );
+                }
+            )
+        })
+    }
+
+    /// Adds target = num / denom (integer division rounded towards 0).
+    pub fn add_division_equality(
+        &self,
+        target: impl Into<LinearExpr>,
+        numerator: impl Into<LinearExpr>,
+        denominator: impl Into<LinearExpr>,
+    ) -> Constraint<'_> {
+        let inner = self.inner.get();
+
+        let target = target.into();
+        let target = &target;
+        let numerator = numerator.into();
+        let numerator = &numerator;
+        let denominator = denominator.into();
+        let denominator = &denominator;
+
+        Constraint::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                target as "const operations_research::sat::LinearExpr*",
+                numerator as "const operations_research::sat::LinearExpr*",
+                denominator as "const operations_research::sat::LinearExpr*"
+            ] -> ConstraintInner as "operations_research::sat::Constraint"
+                {
+                    return inner->AddDivisionEquality(*target, *numerator, *denominator);
+                }
+            )
+        })
+    }
+
+    /// Adds target == abs(expr).
+    pub fn add_abs_equality(
+        &self,
+        target: impl Into<LinearExpr>,
+        expr: impl Into<LinearExpr>,
+    ) -> Constraint<'_> {
+        let inner = self.inner.get();
+
+        let target = target.into();
+        let target = &target;
+        let expr = expr.into();
+        let expr = &expr;
+
+        Constraint::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                target as "const operations_research::sat::LinearExpr*",
+                expr as "const operations_research::sat::LinearExpr*"
+            ] -> ConstraintInner as "operations_research::sat::Constraint"
+                {
+                    return inner->AddAbsEquality(*target, *expr);
+                }
+            )
+        })
+    }
+
+    /// Adds target = var % mod.
+    pub fn add_modulo_equality(
+        &self,
+        target: impl Into<LinearExpr>,
+        var: impl Into<LinearExpr>,
+        modulo: impl Into<LinearExpr>,
+    ) -> Constraint<'_> {
+        let inner = self.inner.get();
+
+        let target = target.into();
+        let target = &target;
+        let var = var.into();
+        let var = &var;
+        let modulo = modulo.into();
+        let modulo = &modulo;
+
+        Constraint::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                target as "const operations_research::sat::LinearExpr*",
+                var as "const operations_research::sat::LinearExpr*",
+                modulo as "const operations_research::sat::LinearExpr*"
+            ] -> ConstraintInner as "operations_research::sat::Constraint"
+                {
+                    return inner->AddModuloEquality(*target, *var, *modulo);
+                }
+            )
+        })
+    }
+
+    /// Adds target == prod(exprs).
+    pub fn add_multiplication_equality<T>(
+        &self,
+        target: impl Into<LinearExpr>,
+        exprs: impl IntoIterator<Item = T>,
+    ) -> Constraint<'_>
+    where
+        T: Into<LinearExpr>,
+    {
+        let inner = self.inner.get();
+
+        let target = target.into();
+        let target = &target;
+        let exprs: Vec<LinearExpr> = exprs.into_iter().map(Into::into).collect();
+        let exprs_ptr = exprs.as_ptr();
+        let exprs_len = exprs.len();
+
+        Constraint::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                target as "const operations_research::sat::LinearExpr*",
+                exprs_ptr as "const operations_research::sat::LinearExpr*",
+                exprs_len as "size_t"
+            ] -> ConstraintInner as "operations_research::sat::Constraint"
+                {
+                    return inner->AddMultiplicationEquality(
+                        *target,
+                        absl::Span<const operations_research::sat::LinearExpr>(exprs_ptr, exprs_len)
+                    );
+                }
+            )
+        })
+    }
+
+    /// Adds a no-overlap constraint that ensures that all present intervals do
+    /// not overlap in time.
+    pub fn add_no_overlap(&self, intervals: &[IntervalVar<'_>]) -> Constraint<'_> {
+        let inner = self.inner.get();
+
+        let intervals_ptr = intervals.as_ptr();
+        let intervals_len = intervals.len();
+
+        Constraint::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                intervals_ptr as "const operations_research::sat::IntervalVar*",
+                intervals_len as "size_t"
+            ] -> ConstraintInner as "operations_research::sat::Constraint"
+                {
+                    return inner->AddNoOverlap(
+                        absl::Span<const operations_research::sat::IntervalVar>(intervals_ptr, intervals_len)
+                    );
+                }
+            )
+        })
+    }
+
+    /// The no_overlap_2d constraint prevents a set of boxes from overlapping.
+    pub fn add_no_overlap_2d(&self) -> NoOverlap2DConstraint<'_> {
+        let inner = self.inner.get();
+
+        NoOverlap2DConstraint::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*"
+            ] -> NoOverlap2DConstraintInner as "operations_research::sat::NoOverlap2DConstraint"
+                {
+                    return inner->AddNoOverlap2D();
+                }
+            )
+        })
+    }
+
+    /// The cumulative constraint
+    ///
+    /// It ensures that for any integer point, the sum of the demands of the
+    /// intervals containing that point does not exceed the capacity.
+    pub fn add_cumulative(&self, capacity: impl Into<LinearExpr>) -> CumulativeConstraint<'_> {
+        let inner = self.inner.get();
+
+        let capacity = capacity.into();
+        let capacity = &capacity;
+
+        CumulativeConstraint::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                capacity as "const operations_research::sat::LinearExpr*"
+            ] -> CumulativeConstraintInner as "operations_research::sat::CumulativeConstraint"
+                {
+                    return inner->AddCumulative(*capacity);
+                }
+            )
+        })
+    }
+
+    /// Adds a linear minimization objective.
+    ///
+    /// Both integer (`LinearExpr`) and floating point (`DoubleLinearExpr`)
+    /// objectives are supported. The penalties of the soft constraints are
+    /// added to it.
+    pub fn minimize(&self, expr: impl self::wrap::Objective) {
+        let inner = self.inner.get();
+
+        match expr.into_objective_expr() {
+            self::wrap::ObjectiveExpr::Linear(expr) => {
+                let expr = &expr;
+
+                unsafe {
+                    cpp!([
+                        inner as "operations_research::sat::CpModelBuilder*",
+                        expr as "const operations_research::sat::LinearExpr*"
+                    ]
+                        {
+                            inner->Minimize(*expr);
+                        }
+                    )
+                }
+            }
+            self::wrap::ObjectiveExpr::Double(expr) => {
+                let expr = &expr;
+
+                unsafe {
+                    cpp!([
+                        inner as "operations_research::sat::CpModelBuilder*",
+                        expr as "const operations_research::sat::DoubleLinearExpr*"
+                    ]
+                        {
+                            inner->Minimize(*expr);
+                        }
+                    )
+                }
+            }
+        }
+        self.add_to_objective(&self.penalties.borrow());
+    }
+
+    /// Adds a linear maximization objective.
+    ///
+    /// Both integer (`LinearExpr`) and floating point (`DoubleLinearExpr`)
+    /// objectives are supported. The penalties of the soft constraints are
+    /// subtracted from it.
+    pub fn maximize(&self, expr: impl self::wrap::Objective) {
+        let inner = self.inner.get();
+
+        match expr.into_objective_expr() {
+            self::wrap::ObjectiveExpr::Linear(expr) => {
+                let expr = &expr;
+
+                unsafe {
+                    cpp!([
+                        inner as "operations_research::sat::CpModelBuilder*",
+                        expr as "const operations_research::sat::LinearExpr*"
+                    ]
+                        {
+                            inner->Maximize(*expr);
+                        }
+                    )
+                }
+            }
+            self::wrap::ObjectiveExpr::Double(expr) => {
+                let expr = &expr;
+
+                unsafe {
+                    cpp!([
+                        inner as "operations_research::sat::CpModelBuilder*",
+                        expr as "const operations_research::sat::DoubleLinearExpr*"
+                    ]
+                        {
+                            inner->Maximize(*expr);
+                        }
+                    )
+                }
+            }
+        }
+        self.add_to_objective(&self.penalties.borrow());
+    }
+
+    /// Removes the objective from the model, including the penalties of the
+    /// soft constraints added so far: they are not added to later objectives.
+    pub fn clear_objective(&self) {
+        *self.penalties.borrow_mut() = LinearExpr::default();
+        let inner = self.inner.get();
+
+        unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*"
+            ]
+                {
+                    inner->ClearObjective();
+                }
+            )
+        }
+    }
+
+    /// Checks whether the model contains an objective.
+    pub fn has_objective(&self) -> bool {
+        let inner = self.inner.get();
+
+        unsafe {
+            cpp!([
+                inner as "const operations_research::sat::CpModelBuilder*"
+            ] -> bool as "bool"
+                {
+                    return inner->HasObjective();
+                }
+            )
+        }
+    }
+
+    /// Adds the soft constraint `expr` in `domain`, whose violation is
+    /// penalized in the objective.
+    ///
+    /// A new literal is true if and only if the constraint is satisfied.
+    /// Otherwise, the violation is the distance from `expr` to the closest bound
+    /// of `domain`, or 1 if `expr` falls in a hole of it, and `penalty` times the
+    /// violation is added to a minimization objective, or subtracted from a
+    /// maximization one. Without objective, the sum of the penalties is
+    /// minimized. The penalties are kept when `minimize()` or `maximize()`
+    /// later replace the objective, but not by `clear_objective()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `penalty` is negative, or if the violation may exceed the
+    /// bounds of a variable.
+    pub fn add_soft_linear(
+        &self,
+        expr: impl Into<LinearExpr>,
+        domain: impl Into<Domain>,
+        penalty: i64,
+    ) -> SoftConstraint<'_> {
+        assert!(
+            penalty >= 0,
+            "the penalty of a soft constraint must be non-negative"
+        );
+        let expr = expr.into();
+        let domain = domain.into();
+
+        // The distance to the domain, which is only meaningful when violated.
+        let (expr_min, expr_max) = self.linear_expr_bounds(&expr);
+        let mut max_distance = 1_i128;
+        if let Some(min) = domain.min().filter(|&min| min > i64::MIN) {
+            max_distance = max_distance.max(i128::from(min) - i128::from(expr_min));
+        }
+        if let Some(max) = domain.max().filter(|&max| max < i64::MAX) {
+            max_distance = max_distance.max(i128::from(expr_max) - i128::from(max));
+        }
+        let max_distance = i64::try_from(max_distance)
+            .ok()
+            .filter(|&max_distance| max_distance <= MAX_VARIABLE_BOUND)
+            .expect("the violation of a soft constraint must fit in a variable");
+
+        let satisfied = self.new_bool_var();
+        self.add_linear_in_domain(&expr, domain.clone())
+            .only_enforce_if(&[satisfied]);
+        self.add_linear_in_domain(&expr, domain.complement())
+            .only_enforce_if(&[!satisfied]);
+
+        let mut distances = vec![LinearExpr::from(1)];
+        if let Some(min) = domain.min().filter(|&min| min > i64::MIN) {
+            distances.push(-expr.clone() + min);
+        }
+        if let Some(max) = domain.max().filter(|&max| max < i64::MAX) {
+            distances.push(expr - max);
+        }
+        let distance = self.new_int_var(Domain::new(1, max_distance));
+        self.add_max_equality(distance, distances);
+
+        let violation = self.new_int_var(Domain::new(0, max_distance));
+        self.add_linear_eq(violation, 0)
+            .only_enforce_if(&[satisfied]);
+        self.add_linear_eq(violation, distance)
+            .only_enforce_if(&[!satisfied]);
+
+        let violation = LinearExpr::from(violation);
+        self.add_penalty(&violation, penalty);
+        SoftConstraint {
+            satisfied,
+            violation,
+            penalty,
+        }
+    }
+
+    /// Adds the soft constraint `lhs == rhs`. See `add_soft_linear()`.
+    pub fn add_soft_linear_eq(
+        &self,
+        lhs: impl Into<LinearExpr>,
+        rhs: impl Into<LinearExpr>,
+        penalty: i64,
+    ) -> SoftConstraint<'_> {
+        self.add_soft_linear(lhs.into() - rhs.into(), 0, penalty)
+    }
+
+    /// Adds the soft constraint `lhs <= rhs`. See `add_soft_linear()`.
+    pub fn add_soft_linear_le(
+        &self,
+        lhs: impl Into<LinearExpr>,
+        rhs: impl Into<LinearExpr>,
+        penalty: i64,
+    ) -> SoftConstraint<'_> {
+        self.add_soft_linear(lhs.into() - rhs.into(), Domain::new(i64::MIN, 0), penalty)
+    }
+
+    /// Adds the soft constraint `lhs >= rhs`. See `add_soft_linear()`.
+    pub fn add_soft_linear_ge(
+        &self,
+        lhs: impl Into<LinearExpr>,
+        rhs: impl Into<LinearExpr>,
+        penalty: i64,
+    ) -> SoftConstraint<'_> {
+        self.add_soft_linear(lhs.into() - rhs.into(), Domain::new(0, i64::MAX), penalty)
+    }
+
+    /// Adds the soft constraint `Or(literals) == true`, whose violation is 1
+    /// when all the literals are false. See `add_soft_linear()`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `penalty` is negative.
+    pub fn add_soft_bool_or(&self, literals: &[BoolVar<'_>], penalty: i64) -> SoftConstraint<'_> {
+        assert!(
+            penalty >= 0,
+            "the penalty of a soft constraint must be non-negative"
+        );
+        let satisfied = self.new_bool_var();
+        self.add_bool_or(literals).only_enforce_if(&[satisfied]);
+        let negated_literals: Vec<_> = literals.iter().map(|&literal| !literal).collect();
+        self.add_bool_and(&negated_literals)
+            .only_enforce_if(&[!satisfied]);
+
+        let violation = LinearExpr::from(!satisfied);
+        self.add_penalty(&violation, penalty);
+        SoftConstraint {
+            satisfied,
+            violation,
+            penalty,
+        }
+    }
+
+    /// Records the penalty of a soft constraint, and adds it to the objective.
+    fn add_penalty(&self, violation: &LinearExpr, penalty: i64) {
+        let penalty = violation.clone() * penalty;
+        self.add_to_objective(&penalty);
+        *self.penalties.borrow_mut() += penalty;
+    }
+
+    /// Returns the bounds of `expr` given the domains of its variables.
+    fn linear_expr_bounds(&self, expr: &LinearExpr) -> (i64, i64) {
+        let mut min = expr.constant();
+        let mut max = expr.constant();
+        for (&index, &coefficient) in expr.variables().iter().zip(expr.coefficients()) {
+            let Some(var) = self.int_var_from_index(index) else {
+                continue;
+            };
+            let domain = var.domain();
+            let (var_min, var_max) = (
+                domain.min().unwrap_or_default().saturating_mul(coefficient),
+                domain.max().unwrap_or_default().saturating_mul(coefficient),
+            );
+            min = min.saturating_add(var_min.min(var_max));
+            max = max.saturating_add(var_min.max(var_max));
+        }
+        (min, max)
+    }
+
+    /// Adds `expr` to a minimization objective, or subtracts it from a
+    /// maximization one.
+    fn add_to_objective(&self, expr: &LinearExpr) {
+        let inner = self.inner.get();
+
+        unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                expr as "const operations_research::sat::LinearExpr*"
+            ]
+                {
+                    auto* proto = inner->MutableProto();
+                    if (proto->has_floating_point_objective()) {
+                        auto* objective = proto->mutable_floating_point_objective();
+                        const double sign = objective->maximize() ? -1.0 : 1.0;
+                        for (int i = 0; i < expr->variables().size(); ++i) {
+                            objective->add_vars(expr->variables()[i]);
+                            objective->add_coeffs(sign * expr->coefficients()[i]);
+                        }
+                        objective->set_offset(objective->offset() + sign * expr->constant());
+                    } else {
+                        // A maximization is stored as the minimization of its
+                        // opposite, with a scaling factor of -1.
+                        auto* objective = proto->mutable_objective();
+                        for (int i = 0; i < expr->variables().size(); ++i) {
+                            objective->add_vars(expr->variables()[i]);
+                            objective->add_coeffs(expr->coefficients()[i]);
+                        }
+                        objective->set_offset(objective->offset() + expr->constant());
+                    }
+                }
+            )
+        }
+    }
+
+    /// Adds a decision strategy on a list of integer expressions.
+    ///
+    /// The strategy is followed in order of the given expressions. Note that
+    /// only the workers using a fixed search (see the `search_branching`
+    /// parameter) are guaranteed to follow it.
+    pub fn add_decision_strategy<T>(
+        &self,
+        exprs: impl IntoIterator<Item = T>,
+        var_strategy: VariableSelectionStrategy,
+        domain_strategy: DomainReductionStrategy,
+    ) where
+        T: Into<LinearExpr>,
+    {
+        let inner = self.inner.get();
+
+        let exprs: Vec<LinearExpr> = exprs.into_iter().map(Into::into).collect();
+        let exprs_ptr = exprs.as_ptr();
+        let exprs_len = exprs.len();
+
+        unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                exprs_ptr as "const operations_research::sat::LinearExpr*",
+                exprs_len as "size_t",
+                var_strategy as "operations_research::sat::DecisionStrategyProto::VariableSelectionStrategy",
+                domain_strategy as "operations_research::sat::DecisionStrategyProto::DomainReductionStrategy"
+            ]
+                {
+                    inner->AddDecisionStrategy(
+                        absl::Span<const operations_research::sat::LinearExpr>(exprs_ptr, exprs_len),
+                        var_strategy,
+                        domain_strategy
+                    );
+                }
+            )
+        }
+    }
+
+    /// Adds hinting to a variable.
+    pub fn add_hint(&self, var: IntVar<'_>, value: i64) {
+        let inner = self.inner.get();
+
+        let var = &var.inner;
+
+        unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                var as "const operations_research::sat::IntVar*",
+                value as "int64_t"
+            ]
+                {
+                    inner->AddHint(*var, value);
+                }
+            )
+        }
+    }
+
+    /// Adds hinting to a Boolean variable.
+    pub fn add_bool_hint(&self, var: BoolVar<'_>, value: bool) {
+        let inner = self.inner.get();
+
+        let var = &var.inner;
+
+        unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                var as "const operations_research::sat::BoolVar*",
+                value as "bool"
+            ]
+                {
+                    inner->AddHint(*var, value);
+                }
+            )
+        }
+    }
+
+    /// Removes all hints.
+    pub fn clear_hints(&self) {
+        let inner = self.inner.get();
+
+        unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*"
+            ]
+                {
+                    inner->ClearHints();
+                }
+            )
+        }
+    }
+
+    /// Replaces all hints with the solution of a previous solve.
+    ///
+    /// This is meant to warm-start a re-solve after small changes to the model:
+    /// every variable that already existed when `response` was produced is hinted
+    /// with its value in that solution. Variables created since then are left
+    /// without hint. It does nothing but clear the hints if `response` holds no
+    /// solution.
+    pub fn hint_from_response(&self, response: &CpSolverResponse) {
+        let inner = self.inner.get();
+
+        unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                response as "const operations_research::sat::CpSolverResponse*"
+            ]
+                {
+                    inner->ClearHints();
+
+                    const int num_variables = std::min(
+                        inner->Proto().variables_size(),
+                        response->solution_size()
+                    );
+                    auto* hint = inner->MutableProto()->mutable_solution_hint();
+                    for (int i = 0; i < num_variables; ++i) {
+                        hint->add_vars(i);
+                        hint->add_values(response->solution(i));
+                    }
+                }
+            )
+        }
+    }
+
+    /// Adds a literal to the model as assumptions.
+    pub fn add_assumption(&self, literal: BoolVar<'_>) {
+        let inner = self.inner.get();
+
+        let literal = &literal.inner;
+
+        unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                literal as "const operations_research::sat::BoolVar*"
+            ]
+                {
+                    inner->AddAssumption(*literal);
+                }
+            )
+        }
+    }
+
+    /// Adds multiple literals to the model as assumptions.
+    pub fn add_assumptions(&self, literals: &[BoolVar<'_>]) {
+        let inner = self.inner.get();
+
+        let literals_ptr = literals.as_ptr();
+        let literals_len = literals.len();
+
+        unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                literals_ptr as "const operations_research::sat::BoolVar*",
+                literals_len as "size_t"
+            ]
+                {
+                    inner->AddAssumptions(
+                        absl::Span<const operations_research::sat::BoolVar>(literals_ptr, literals_len)
+                    );
+                }
+            )
+        }
+    }
+
+    /// Remove all assumptions from the model.
+    pub fn clear_assumptions(&self) {
+        let inner = self.inner.get();
+
+        unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*"
+            ]
+                {
+                    inner->ClearAssumptions();
+                }
+            )
+        }
+    }
+
+    /// Returns the number of variables in the model.
+    pub fn num_variables(&self) -> c_int {
+        let inner = self.inner.get();
+
+        unsafe {
+            cpp!([
+                inner as "const operations_research::sat::CpModelBuilder*"
+            ] -> c_int as "int"
+                {
+                    return inner->Proto().variables_size();
+                }
+            )
+        }
+    }
+
+    /// Returns the number of constraints in the model, including interval
+    /// variables.
+    pub fn num_constraints(&self) -> c_int {
+        let inner = self.inner.get();
+
+        unsafe {
+            cpp!([
+                inner as "const operations_research::sat::CpModelBuilder*"
+            ] -> c_int as "int"
+                {
+                    return inner->Proto().constraints_size();
+                }
+            )
+        }
+    }
+
+    /// Returns the integer variable from its index in the proto, or `None` if
+    /// the index is out of range.
+    pub fn int_var_from_index(&self, index: c_int) -> Option<IntVar<'_>> {
+        if !(0..self.num_variables()).contains(&index) {
+            return None;
+        }
+
+        let inner = self.inner.get();
+
+        Some(IntVar::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                index as "int"
+            ] -> IntVarInner as "operations_research::sat::IntVar"
+                {
+                    return inner->GetIntVarFromProtoIndex(index);
+                }
+            )
+        }))
+    }
+
+    /// Returns the Boolean variable from its index in the proto, or `None` if
+    /// the index is out of range or the variable domain is not included in
+    /// [0, 1].
+    pub fn bool_var_from_index(&self, index: c_int) -> Option<BoolVar<'_>> {
+        let var = self.int_var_from_index(index)?;
+        if !var.domain().is_included_in(&Domain::new(0, 1)) {
+            return None;
+        }
+
+        Some(self.bool_var_from_literal_index(index))
+    }
+
+    /// Returns the interval variable from its index in the proto, or `None` if
+    /// the index is out of range or does not refer to an interval.
+    pub fn interval_var_from_index(&self, index: c_int) -> Option<IntervalVar<'_>> {
+        if !(0..self.num_constraints()).contains(&index) {
+            return None;
+        }
+
+        let inner = self.inner.get();
+
+        let is_interval = unsafe {
+            cpp!([
+                inner as "const operations_research::sat::CpModelBuilder*",
+                index as "int"
+            ] -> bool as "bool"
+                {
+                    return inner->Proto().constraints(index).has_interval();
+                }
+            )
+        };
+        if !is_interval {
+            return None;
+        }
+
+        Some(IntervalVar::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                index as "int"
+            ] -> IntervalVarInner as "operations_research::sat::IntervalVar"
+                {
+                    return inner->GetIntervalVarFromProtoIndex(index);
+                }
+            )
+        }))
+    }
+
+    /// Returns the constraint from its index in the proto, or `None` if the
+    /// index is out of range.
+    pub fn constraint_from_index(&self, index: c_int) -> Option<Constraint<'_>> {
+        if !(0..self.num_constraints()).contains(&index) {
+            return None;
+        }
+
+        let inner = self.inner.get();
+
+        Some(Constraint::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                index as "int"
+            ] -> ConstraintInner as "operations_research::sat::Constraint"
+                {
+                    // The constructor from a proto is only reachable by subclasses.
+                    struct ConstraintFromProto : operations_research::sat::Constraint {
+                        explicit ConstraintFromProto(operations_research::sat::ConstraintProto* proto)
+                            : operations_research::sat::Constraint(proto) {}
+                    };
+                    return ConstraintFromProto(inner->MutableProto()->mutable_constraints(index));
+                }
+            )
+        }))
+    }
+
+    /// Returns the first integer variable with the given name.
+    pub fn int_var_by_name(&self, name: &str) -> Option<IntVar<'_>> {
+        self.variable_index_by_name(name)
+            .and_then(|index| self.int_var_from_index(index))
+    }
+
+    /// Returns the first Boolean variable with the given name.
+    pub fn bool_var_by_name(&self, name: &str) -> Option<BoolVar<'_>> {
+        self.variable_index_by_name(name)
+            .and_then(|index| self.bool_var_from_index(index))
+    }
+
+    /// Returns the first interval variable with the given name.
+    pub fn interval_var_by_name(&self, name: &str) -> Option<IntervalVar<'_>> {
+        self.constraint_index_by_name(name)
+            .and_then(|index| self.interval_var_from_index(index))
+    }
+
+    /// Returns the first constraint with the given name.
+    pub fn constraint_by_name(&self, name: &str) -> Option<Constraint<'_>> {
+        self.constraint_index_by_name(name)
+            .and_then(|index| self.constraint_from_index(index))
+    }
+
+    fn variable_index_by_name(&self, name: &str) -> Option<c_int> {
+        let inner = self.inner.get();
+
+        let name_ptr = name.as_ptr();
+        let name_len = name.len();
+
+        let index = unsafe {
+            cpp!([
+                inner as "const operations_research::sat::CpModelBuilder*",
+                name_ptr as "const char*",
+                name_len as "size_t"
+            ] -> c_int as "int"
+                {
+                    const absl::string_view name(name_ptr, name_len);
+                    const auto& variables = inner->Proto().variables();
+                    for (int i = 0; i < variables.size(); ++i) {
+                        if (variables[i].name() == name) {
+                            return i;
+                        }
+                    }
+                    return -1;
+                }
+            )
+        };
+        (index >= 0).then_some(index)
+    }
+
+    fn constraint_index_by_name(&self, name: &str) -> Option<c_int> {
+        let inner = self.inner.get();
+
+        let name_ptr = name.as_ptr();
+        let name_len = name.len();
+
+        let index = unsafe {
+            cpp!([
+                inner as "const operations_research::sat::CpModelBuilder*",
+                name_ptr as "const char*",
+                name_len as "size_t"
+            ] -> c_int as "int"
+                {
+                    const absl::string_view name(name_ptr, name_len);
+                    const auto& constraints = inner->Proto().constraints();
+                    for (int i = 0; i < constraints.size(); ++i) {
+                        if (constraints[i].name() == name) {
+                            return i;
+                        }
+                    }
+                    return -1;
+                }
+            )
+        };
+        (index >= 0).then_some(index)
+    }
+
+    /// Returns the Boolean literal from its index in the proto, following the
+    /// negated reference convention of `BoolVar::index()`.
+    fn bool_var_from_literal_index(&self, index: c_int) -> BoolVar<'_> {
+        let inner = self.inner.get();
+
+        BoolVar::from_inner(unsafe {
+            cpp!([
+                inner as "operations_research::sat::CpModelBuilder*",
+                index as "int"
+            ] -> BoolVarInner as "operations_research::sat::BoolVar"
+                {
+                    if (index >= 0) {
+                        return inner->GetBoolVarFromProtoIndex(index);
+                    }
+                    return inner->GetBoolVarFromProtoIndex(-index - 1).Not();
+                }
+            )
+        })
+    }
+
+    /// Verifies that the given model satisfies all the properties described in
+    /// the proto comments.
+    ///
+    /// Returns the description of the first issue found, if any.
+    pub fn validate(&self) -> Result<(), String> {
+        let inner = self.inner.get();
+
+        let error: String = unsafe {
+            cpp!([
+                inner as "const operations_research::sat::CpModelBuilder*"
+            ] -> CxxString as "std::unique_ptr<std::string>"
+                {
+                    return std::make_unique<std::string>(operations_research::sat::ValidateCpModel(inner->Proto()));
+                }
+            )
+        }
+        .into();
+
+        if error.is_empty() {
+            Ok(())
+        } else {
+            Err(error)
+        }
+    }
+
+    /// Returns a string with some statistics on the given CpModelProto.
+    pub fn model_stats(&self) -> String {
+        let inner = self.inner.get();
+
+        unsafe {
+            cpp!([
+                inner as "const operations_research::sat::CpModelBuilder*"
+            ] -> CxxString as "std::unique_ptr<std::string>"
+                {
+                    return std::make_unique<std::string>(operations_research::sat::CpModelStats(inner->Proto()));
+                }
+            )
+        }
+        .into()
+    }
+
+    /// Solves the given model, and returns an instance of CpSolverResponse.
+    pub fn solve(&self) -> CpSolverResponse {
+        let inner = self.inner.get();
+
+        unsafe {
+            cpp!([
+                inner as "const operations_research::sat::CpModelBuilder*"
+            ] -> CpSolverResponse as "operations_research::sat::CpSolverResponse"
+                {
+                    return operations_research::sat::Solve(inner->Build());
+                }
+            )
+        }
+    }
+
+    /// Solves the given model with the given parameters, and returns an instance
+    /// of CpSolverResponse.
+    pub fn solve_with_parameters(&self, parameters: &SatParameters) -> CpSolverResponse {
+        let inner = self.inner.get();
+
+        unsafe {
+            cpp!([
+                inner as "const operations_research::sat::CpModelBuilder*",
+                parameters as "const operations_research::sat::SatParameters*"
+            ] -> CpSolverResponse as "operations_research::sat::CpSolverResponse"
+                {
+                    return operations_research::sat::SolveWithParameters(inner->Build(), *parameters);
+                }
+            )
+        }
+    }
+
+    /// Solves the given model with the given parameters, calling `observer` on
+    /// each feasible solution found during the search.
+    ///
+    /// With an objective, each reported solution improves on the previous one.
+    /// Returning `ControlFlow::Break(())` from the observer stops the search as
+    /// soon as possible; the observer will not be called again afterwards.
+    ///
+    /// The search runs on a background thread while the observer is called on
+    /// the calling thread, so it can borrow anything, and calls never overlap.
+    /// All of them happen before this method returns. If the observer panics,
+    /// the search is stopped and the panic is resumed once it is over.
+    pub fn solve_with_observer<F>(
+        &self,
+        parameters: &SatParameters,
+        mut observer: F,
+    ) -> CpSolverResponse
+    where
+        F: FnMut(&CpSolverResponse) -> ControlFlow<()>,
+    {
+        let model_proto = self.build();
+        let stopped = AtomicBool::new(false);
+        let (sender, receiver) = mpsc::sync_channel(0);
+
+        thread::scope(|scope| {
+            let search = scope.spawn(|| {
+                solve_proto_with_observer(&model_proto, parameters, &stopped, move |response| {
+                    match sender.send(response.clone()) {
+                        Ok(()) => ControlFlow::Continue(()),
+                        Err(_) => ControlFlow::Break(()),
+                    }
+                })
+            });
+
+            // Dropping the receiver, including while unwinding from a panic of
+            // the observer, unblocks and stops the search.
+            for response in receiver {
+                if observer(&response).is_break() {
+                    stopped.store(true, Ordering::SeqCst);
+                    break;
+                }
+            }
+
+            search
+                .join()
+                .unwrap_or_else(|payload| panic::resume_unwind(payload))
+        })
+    }
+
+    /// Enumerates all the solutions of the model, in the order they are found.
+    ///
+    /// The search runs on a background thread on a snapshot of the current
+    /// model, with `enumerate_all_solutions` enabled on top of the given
+    /// parameters. The search is paused while the next solution waits to be
+    /// consumed, and it is stopped when the iterator is dropped. Once the
+    /// iterator is exhausted, `Solutions::status()` tells whether all the
+    /// solutions were enumerated, or why the search ended.
+    ///
+    /// Enumerating the solutions of a model with an objective, including one
+    /// created by the soft constraints, is not meaningful: the iterator is then
+    /// empty and its status is `ModelInvalid`.
+    pub fn solutions(&self, parameters: &SatParameters) -> Solutions {
+        if self.has_objective() {
+            let response = unsafe {
+                cpp!([
+                ] -> CpSolverResponse as "operations_research::sat::CpSolverResponse"
+                    {
+                        operations_research::sat::CpSolverResponse response;
+                        response.set_status(operations_research::sat::CpSolverStatus::MODEL_INVALID);
+                        response.set_solution_info(
+                            "solutions can only be enumerated for a model without objective");
+                        return response;
+                    }
+                )
+            };
+            return Solutions {
+                receiver: None,
+                search: None,
+                response: Some(response),
+                stopped: Arc::new(AtomicBool::new(false)),
+            };
+        }
+
+        let model_proto = self.build();
+        let mut parameters = parameters.clone();
+        parameters.set_enumerate_all_solutions(true);
+
+        let stopped = Arc::new(AtomicBool::new(false));
+        let (sender, receiver) = mpsc::sync_channel(0);
+
+        let search = {
+            let stopped = stopped.clone();
+            thread::spawn(move || {
+                solve_proto_with_observer(&model_proto, &parameters, &stopped, |response| {
+                    match sender.send(SolutionSnapshot::from_response(response)) {
+                        Ok(()) => ControlFlow::Continue(()),
+                        Err(_) => ControlFlow::Break(()),
+                    }
+                })
+            })
+        };
+
+        Solutions {
+            receiver: Some(receiver),
+            search: Some(search),
+            response: None,
+            stopped,
+        }
+    }
+
+    /// Returns a copy of the underlying CpModelProto.
+    pub(crate) fn build(&self) -> CpModelProto {
+        let inner = self.inner.get();
+
+        unsafe {
+            cpp!([
+                inner as "const operations_research::sat::CpModelBuilder*"
+            ] -> CpModelProto as "operations_research::sat::CpModelProto"
+                {
+                    return inner->Build();
+                }
+            )
+        }
+    }
+
+    /// Returns a copy of the model, with the same variables and the same
+    /// penalties of soft constraints.
+    pub(crate) fn copy(&self) -> Self {
+        let model = Self::from_bytes(&self.export_to_bytes())
+            .expect("a model can be copied through its serialized form");
+        *model.penalties.borrow_mut() = self.penalties.borrow().clone();
+        model
+    }
+}
+
+cpp_class!(
+    #[doc(hidden)]
+    pub(crate) unsafe struct CpModelProto as "operations_research::sat::CpModelProto"
+);
+
+/// Solves `model_proto`, calling `observer` on each feasible solution until it
+/// breaks or `stopped` is set.
+///
+/// The observer runs on the solver worker threads. A panic is caught before it
+/// reaches the native frames, stops the search, and is resumed once the search
+/// is over.
+fn solve_proto_with_observer<F>(
+    model_proto: &CpModelProto,
+    parameters: &SatParameters,
+    stopped: &AtomicBool,
+    mut observer: F,
+) -> CpSolverResponse
+where
+    F: FnMut(&CpSolverResponse) -> ControlFlow<()> + Send,
+{
+    let mut panic_payload = None;
+    let mut callback = |response: *const CpSolverResponse| -> u8 {
+        let response = unsafe { &*response };
+        match panic::catch_unwind(AssertUnwindSafe(|| observer(response))) {
+            Ok(flow) => flow.is_break().into(),
+            Err(payload) => {
+                panic_payload = Some(payload);
+                1
+            }
+        }
+    };
+    let closure = ClosureMut1::new(&mut callback);
+    let &f_ptr = closure.code_ptr();
+
+    let response = unsafe {
+        cpp!([
+            model_proto as "const operations_research::sat::CpModelProto*",
+            parameters as "const operations_research::sat::SatParameters*",
+            stopped as "std::atomic<bool>*",
+            f_ptr as "const void*"
+        ] -> CpSolverResponse as "operations_research::sat::CpSolverResponse"
+            {
+                uint8_t (*f)(const operations_research::sat::CpSolverResponse*) =
+                    (uint8_t (*)(const operations_research::sat::CpSolverResponse*))f_ptr;
+
+                operations_research::sat::Model model;
+                model.Add(operations_research::sat::NewSatParameters(*parameters));
+                model.GetOrCreate<operations_research::TimeLimit>()
+                    ->RegisterExternalBooleanAsLimit(stopped);
+
+                model.Add(operations_research::sat::NewFeasibleSolutionObserver(
+                    [f, stopped](const operations_research::sat::CpSolverResponse& response) {
+                        if (*stopped) {
+                            return;
+                        }
+                        if (f(&response)) {
+                            *stopped = true;
+                        }
+                    }
+                ));
+                return operations_research::sat::SolveCpModel(*model_proto, &model);
+            }
+        )
+    };
+
+    drop(closure);
+    if let Some(payload) = panic_payload {
+        panic::resume_unwind(payload);
+    }
+    response
+}
+
+/// Whether a model file uses the protobuf text format, following the
+/// convention of `CpModelBuilder::export_to_file()`.
+fn is_text_proto_file(path: &Path) -> bool {
+    path.extension().is_some_and(|extension| extension == "txt")
+}
+
+fn invalid_model_file(path: &Path) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("failed to parse CpModelProto from {}", path.display()),
+    )
+}
+
+cpp_class!(
+    #[doc(hidden)]
+    unsafe struct IntVarInner as "operations_research::sat::IntVar"
+);
+
+/// An integer variable.
+///
+/// This class wraps an IntegerVariableProto.
+/// This can only be constructed via `CpModelBuilder::new_int_var()`.
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct IntVar<'model> {
+    /// Original variable
+    inner: IntVarInner,
+    /// Lifetime limiter for model
+    _model: PhantomData<&'model CpModelBuilder>,
+}
+
+impl<'model> IntVar<'model> {
+    fn from_inner(inner: IntVarInner) -> Self {
+        Self {
+            inner,
+            _model: PhantomData,
+        }
+    }
+
+    /// Sets the name of the variable.
+    pub fn with_name(mut self, name: &str) -> Self {
+        let inner = &mut self.inner;
+
+        let name_ptr = name.as_ptr();
+        let name_len = name.len();
+
+        unsafe {
+            cpp!([
+                inner as "operations_research::sat::IntVar*",
+                name_ptr as "const char*",
+                name_len as "size_t"
+            ]
+                {
+                    inner->WithName(std::string(name_ptr, name_len));
+                }
+            )
+        }
+        self
+    }
+
+    /// Returns the name of the variable.
+    pub fn name(&self) -> String {
+        let inner = &self.inner;
+
+        unsafe {
+            cpp!([
+                inner as "const operations_research::sat::IntVar*"
+            ] -> CxxString as "std::unique_ptr<std::string>"
+                {
+                    return std::make_unique<std::string>(inner->Name());
+                }
+            )
+        }
+        .into()
+    }
+
+    /// Returns the domain of the variable.
+    pub fn domain(&self) -> Domain {
+        let inner = &self.inner;
+
+        unsafe {
+            cpp!([
+                inner as "const operations_research::sat::IntVar*"
+            ] -> Domain as "operations_research::Domain"
+                {
+                    return inner->Domain();
+                }
+            )
+        }
+    }
+
+    /// Returns the index of the variable in the model. This will be non-negative.
+    pub fn index(&self) -> c_int {
+        let inner = &self.inner;
+
+        unsafe {
+            cpp!([
+                inner as "const operations_research::sat::IntVar*"
+            ] -> c_int as "int"
+                {
+                    return inner->index();
+                }
+            )
+        }
+    }
+}
+
+cpp_class!(
+    #[doc(hidden)]
+    unsafe struct BoolVarInner as "operations_research::sat::BoolVar"
+);
+
+/// A Boolean variable.
+///
+/// This class refer to an IntegerVariableProto with domain [0, 1] or to its
+/// logical negation (Not). This is called a Boolean Literal in other context.
+///
+/// This can only be constructed via `CpModelBuilder::new_bool_var()`.
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct BoolVar<'model> {
+    /// Original variable
+    inner: BoolVarInner,
+    /// Lifetime limiter for model
+    _model: PhantomData<&'model CpModelBuilder>,
+}
+
+impl<'model> BoolVar<'model> {
+    fn from_inner(inner: BoolVarInner) -> Self {
+        Self {
+            inner,
+            _model: PhantomData,
+        }
+    }
+
+    /// Sets the name of the variable.
+    /// Note that this will always set the "positive" version of this Boolean.
+    pub fn with_name(mut self, name: &str) -> Self {
+        let inner = &mut self.inner;
+
+        let name_ptr = name.as_ptr();
+        let name_len = name.len();
+
+        unsafe {
+            cpp!([
+                inner as "operations_research::sat::BoolVar*",
+                name_ptr as "const char*",
+                name_len as "size_t"
+            ]
+                {
+                    inner->WithName(std::string(name_ptr, name_len));
+                }
+            )
+        }
+        self
+    }
+
+    /// Returns the name of the variable.
+    pub fn name(&self) -> String {
+        let inner = &self.inner;
+
+        unsafe {
+            cpp!([
+                inner as "const operations_research::sat::BoolVar*"
+            ] -> CxxString as "std::unique_ptr<std::string>"
+                {
+                    return std::make_unique<std::string>(inner->Name());
+                }
+            )
+        }
+        .into()
+    }
+
+    /// Returns the index of the variable in the model.
+    ///
+    /// Warning: If the variable is the negation of another variable `v`, its
+    /// index is `-v.index() - 1`. So this can be negative.
+    pub fn index(&self) -> c_int {
+        let inner = &self.inner;
+
+        unsafe {
+            cpp!([
+                inner as "const operations_research::sat::BoolVar*"
+            ] -> c_int as "int"
+                {
+                    return inner->index();
+                }
+            )
+        }
+    }
+}
+
+impl<'model> Not for BoolVar<'model> {
+    type Output = Self;
+
+    /// Returns the logical negation of the current Boolean variable.
+    fn not(self) -> Self::Output {
+        let inner = &self.inner;
+
+        Self::from_inner(unsafe {
+            cpp!([
+                inner as "const operations_research::sat::BoolVar*"
+            ] -> BoolVarInner as "operations_research::sat::BoolVar"
+                {
+                    return inner->Not();
+                }
+            )
+        })
+    }
+}
+
+impl<'model> From<BoolVar<'model>> for IntVar<'model> {
+    /// Casts a BoolVar into an IntVar.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the Boolean variable is the negation of another variable.
+    fn from(value: BoolVar<'model>) -> Self {
+        assert!(
+            value.index() >= 0,
+            "a negated Boolean variable cannot be cast into an integer variable"
+        );
+        let var = &value.inner;
+
+        IntVar::from_inner(unsafe {
+            cpp!([
+                var as "const operations_research::sat::BoolVar*"
+            ] -> IntVarInner as "operations_research::sat::IntVar"
+                {
+                    return operations_research::sat::IntVar(*var);
+                }
+            )
+        })
+    }
+}
+
+cpp_class!(
+    #[doc(hidden)]
+    unsafe struct IntervalVarInner as "operations_research::sat::IntervalVar"
+);
+
+/// Represents a Interval variable.
+///
+/// An interval variable is both a constraint and a variable. It is defined by
+/// three objects: start, size, and end. All three can be an integer variable, a
+/// constant, or an affine expression.
+///
+/// It is a constraint because, internally, it enforces that start + size == end.
+///
+/// It is also a variable as it can appear in specific scheduling constraints:
+/// NoOverlap, NoOverlap2D, Cumulative.
+///
+/// Optionally, a presence literal can be added to this constraint. This presence
+/// literal is understood by the same constraints. These constraints ignore
+/// interval variables with precence literals assigned to false. Conversely,
+/// these constraints will also set these presence literals to false if they
+/// cannot fit these intervals into the schedule.
+///
+/// It can only be constructed via `CpModelBuilder::new_interval_var()`.
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct IntervalVar<'model> {
+    /// Original variable
+    inner: IntervalVarInner,
+    /// Lifetime limiter for model
+    _model: PhantomData<&'model CpModelBuilder>,
+}
+
+impl<'model> IntervalVar<'model> {
+    fn from_inner(inner: IntervalVarInner) -> Self {
+        Self {
+            inner,
+            _model: PhantomData,
+        }
+    }
+
+    /// Sets the name of the variable.
+    pub fn with_name(mut self, name: &str) -> Self {
+        let inner = &mut self.inner;
+
+        let name_ptr = name.as_ptr();
+        let name_len = name.len();
+
+        unsafe {
+            cpp!([
+                inner as "operations_research::sat::IntervalVar*",
+                name_ptr as "const char*",
+                name_len as "size_t"
+            ]
+                {
+                    inner->WithName(std::string(name_ptr, name_len));
+                }
+            )
+        }
+        self
+    }
+
+    /// Returns the name of the interval (or the empty string if not set).
+    pub fn name(&self) -> String {
+        let inner = &self.inner;
+
+        unsafe {
+            cpp!([
+                inner as "const operations_research::sat::IntervalVar*"
+            ] -> CxxString as "std::unique_ptr<std::string>"
+                {
+                    return std::make_unique<std::string>(inner->Name());
+                }
+            )
+        }
+        .into()
+    }
+
+    /// Returns the start linear expression. Note that this rebuilds the
+    /// expression each time this method is called.
+    pub fn start_expr(&self) -> LinearExpr {
+        let inner = &self.inner;
+
+        unsafe {
+            cpp!([
+                inner as "const operations_research::sat::IntervalVar*"
+            ] -> LinearExpr as "operations_research::sat::LinearExpr"
+                {
+                    return inner->StartExpr();
+                }
+            )
+        }
+    }
+
+    /// Returns the size linear expression. Note that this rebuilds the
+    /// expression each time this method is called.
+    pub fn size_expr(&self) -> LinearExpr {
+        let inner = &self.inner;
+
+        unsafe {
+            cpp!([
+                inner as "const operations_research::sat::IntervalVar*"
+            ] -> LinearExpr as "operations_research::sat::LinearExpr"
+                {
+                    return inner->SizeExpr();
+                }
+            )
+        }
+    }
+
+    /// Returns the end linear expression. Note that this rebuilds the
+    /// expression each time this method is called.
+    pub fn end_expr(&self) -> LinearExpr {
+        let inner = &self.inner;
+
+        unsafe {
+            cpp!([
+                inner as "const operations_research::sat::IntervalVar*"
+            ] -> LinearExpr as "operations_research::sat::LinearExpr"
+                {
+                    return inner->EndExpr();
+                }
+            )
+        }
+    }
+
+    /// Returns a BoolVar indicating the presence of this interval.
+    ///
+    /// It returns a constant true literal if the interval is not optional.
+    pub fn presence_bool_var(&self) -> BoolVar<'model> {
+        let inner = &self.inner;
+
+        BoolVar::from_inner(unsafe {
+            cpp!([
+                inner as "const operations_research::sat::IntervalVar*"
+            ] -> BoolVarInner as "operations_research::sat::BoolVar"
+                {
+                    return inner->PresenceBoolVar();
+                }
+            )
+        })
+    }
+
+    /// Returns the index of the interval constraint in the model.
+    pub fn index(&self) -> c_int {
+        let inner = &self.inner;
+
+        unsafe {
+            cpp!([
+                inner as "const operations_research::sat::IntervalVar*"
+            ] -> c_int as "int"
+                {
+                    return inner->index();
+                }
+            )
+        }
+    }
+}
+
+cpp_class!(
+    #[doc(hidden)]
+    unsafe struct ConstraintInner as "operations_research::sat::Constraint"
+);
+
+/// A constraint.
+///
+/// This class enables you to modify the constraint that was previously added to
+/// the model.
+///
+/// The constraint must be built using the different `CpModelBuilder::add_xxx()`
+/// methods.
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct Constraint<'model> {
+    /// Original constraint
+    inner: ConstraintInner,
+    /// Lifetime limiter for model
+    _model: PhantomData<&'model CpModelBuilder>,
+}
+
+impl<'model> Constraint<'model> {
+    fn from_inner(inner: ConstraintInner) -> Self {
+        Self {
+            inner,
+            _model: PhantomData,
+        }
+    }
+
+    /// The constraint will be enforced iff all literals listed here are true.
+    ///
+    /// If this is empty, then the constraint will always be enforced. An enforced
+    /// constraint must be satisfied, and an un-enforced one will simply be
+    /// ignored.
+    ///
+    /// This is also called half-reification. To have an equivalence between a
+    /// literal and a constraint (full reification), one must add both a
+    /// constraint (controlled by a literal l) and its negation (controlled by the
+    /// negation of l).
+    ///
+    /// Important: as of September 2018, only a few constraint support enforcement:
+    /// - bool_or, bool_and, linear: fully supported.
+    /// - interval: only support a single enforcement literal.
+    /// - other: no support (but can be added on a per-demand basis).
+    pub fn only_enforce_if(mut self, literals: &[BoolVar<'model>]) -> Self {
+        let inner = &mut self.inner;
+
+        let literals_ptr = literals.as_ptr();
+        let literals_len = literals.len();
+
+        unsafe {
+            cpp!([
+                inner as "operations_research::sat::Constraint*",
+                literals_ptr as "const operations_research::sat::BoolVar*",
+                literals_len as "size_t"
+            ]
+                {
+                    inner->OnlyEnforceIf(
+                        absl::Span<const operations_research::sat::BoolVar>(literals_ptr, literals_len)
+                    );
+                }
+            )
+        }
+        self
+    }
+
+    /// Sets the name of the constraint.
+    pub fn with_name(mut self, name: &str) -> Self {
+        let inner = &mut self.inner;
+
+        let name_ptr = name.as_ptr();
+        let name_len = name.len();
+
+        unsafe {
+            cpp!([
+                inner as "operations_research::sat::Constraint*",
+                name_ptr as "const char*",
+                name_len as "size_t"
+            ]
+                {
+                    inner->WithName(std::string(name_ptr, name_len));
+                }
+            )
+        }
+        self
+    }
+
+    /// Returns the name of the constraint (or the empty string if not set).
+    pub fn name(&self) -> String {
+        let inner = &self.inner;
+
+        unsafe {
+            cpp!([
+                inner as "const operations_research::sat::Constraint*"
+            ] -> CxxString as "std::unique_ptr<std::string>"
+                {
+                    return std::make_unique<std::string>(inner->Name());
+                }
+            )
+        }
+        .into()
+    }
+}
+
+/// Gives specialized constraint handles access to the generic `Constraint`
+/// methods.
+///
+/// The C++ specialized constraints only add methods on top of `Constraint`, so
+/// both handles share the same layout.
+macro_rules! impl_constraint_deref {
+    ($ty:ident) => {
+        impl<'model> Deref for $ty<'model> {
+            type Target = Constraint<'model>;
+
+            fn deref(&self) -> &Self::Target {
+                // SAFETY: both handles are `repr(transparent)` wrappers around C++
+                // classes sharing the layout of `operations_research::sat::Constraint`.
+                unsafe { &*(self as *const Self as *const Constraint<'model>) }
+            }
+        }
+
+        impl<'model> DerefMut for $ty<'model> {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                // SAFETY: see `Deref`.
+                unsafe { &mut *(self as *mut Self as *mut Constraint<'model>) }
+            }
+        }
+
+        impl<'model> From<$ty<'model>> for Constraint<'model> {
+            fn from(value: $ty<'model>) -> Self {
+                *value
+            }
+        }
+    };
+}
+
+/// Specialized circuit constraint.
+///
+/// This constraint allows adding arcs to the circuit constraint incrementally.
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct CircuitConstraint<'model> {
+    /// Original constraint
+    inner: CircuitConstraintInner,
+    /// Lifetime limiter for model
+    _model: PhantomData<&'model CpModelBuilder>,
+}
+
+cpp_class!(
+    #[doc(hidden)]
+    unsafe struct CircuitConstraintInner as "operations_research::sat::CircuitConstraint"
+);
+
+impl<'model> CircuitConstraint<'model> {
+    fn from_inner(inner: CircuitConstraintInner) -> Self {
+        Self {
+            inner,
+            _model: PhantomData,
+        }
+    }
+
+    /// Add an arc to the circuit.
+    ///
+    /// The arc goes from `tail` to `head` and is selected iff `literal` is true.
+    pub fn add_arc(&mut self, tail: c_int, head: c_int, literal: BoolVar<'model>) {
+        let inner = &mut self.inner;
+
+        let literal = &literal.inner;
+
+        unsafe {
+            cpp!([
+                inner as "operations_research::sat::CircuitConstraint*",
+                tail as "int",
+                head as "int",
+                literal as "const operations_research::sat::BoolVar*"
+            ]
+                {
+                    inner->AddArc(tail, head, *literal);
+                }
+            )
+        }
+    }
+}
+
+impl_constraint_deref!(CircuitConstraint);
+
+/// Specialized circuit constraint.
+///
+/// This constraint allows adding arcs to the multiple circuit constraint
+/// incrementally.
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct MultipleCircuitConstraint<'model> {
+    /// Original constraint
+    inner: MultipleCircuitConstraintInner,
+    /// Lifetime limiter for model
+    _model: PhantomData<&'model CpModelBuilder>,
+}
+
+cpp_class!(
+    #[doc(hidden)]
+    unsafe struct MultipleCircuitConstraintInner as "operations_research::sat::MultipleCircuitConstraint"
+);
+
+impl<'model> MultipleCircuitConstraint<'model> {
+    fn from_inner(inner: MultipleCircuitConstraintInner) -> Self {
+        Self {
+            inner,
+            _model: PhantomData,
+        }
+    }
+
+    /// Add an arc to the circuit.
+    ///
+    /// The arc goes from `tail` to `head` and is selected iff `literal` is true.
+    pub fn add_arc(&mut self, tail: c_int, head: c_int, literal: BoolVar<'model>) {
+        let inner = &mut self.inner;
+
+        let literal = &literal.inner;
+
+        unsafe {
+            cpp!([
+                inner as "operations_research::sat::MultipleCircuitConstraint*",
+                tail as "int",
+                head as "int",
+                literal as "const operations_research::sat::BoolVar*"
+            ]
+                {
+                    inner->AddArc(tail, head, *literal);
+                }
+            )
+        }
+    }
+}
+
+impl_constraint_deref!(MultipleCircuitConstraint);
+
+/// Specialized assignment constraint.
+///
+/// This constraint allows adding tuples to the allowed/forbidden assignment
+/// constraint incrementally.
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct TableConstraint<'model> {
+    /// Original constraint
+    inner: TableConstraintInner,
+    /// Lifetime limiter for model
+    _model: PhantomData<&'model CpModelBuilder>,
+}
+
+cpp_class!(
+    #[doc(hidden)]
+    unsafe struct TableConstraintInner as "operations_research::sat::TableConstraint"
+);
+
+impl<'model> TableConstraint<'model> {
+    fn from_inner(inner: TableConstraintInner) -> Self {
+        Self {
+            inner,
+            _model: PhantomData,
+        }
+    }
+
+    /// Adds a tuple of possible values to the constraint.
+    pub fn add_tuple(&mut self, tuple: &[i64]) {
+        let inner = &mut self.inner;
+
+        let tuple_ptr = tuple.as_ptr();
+        let tuple_len = tuple.len();
+
+        unsafe {
+            cpp!([
+                inner as "operations_research::sat::TableConstraint*",
+                tuple_ptr as "const int64_t*",
+                tuple_len as "size_t"
+            ]
+                {
+                    inner->AddTuple(absl::Span<const int64_t>(tuple_ptr, tuple_len));
+                }
+            )
+        }
+    }
+}
+
+impl_constraint_deref!(TableConstraint);
+
+/// Specialized reservoir constraint.
+///
+/// This constraint allows adding emptying/refilling events to the reservoir
+/// constraint incrementally.
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct ReservoirConstraint<'model> {
+    /// Original constraint
+    inner: ReservoirConstraintInner,
+    /// Lifetime limiter for model
+    _model: PhantomData<&'model CpModelBuilder>,
+}
+
+cpp_class!(
+    #[doc(hidden)]
+    unsafe struct ReservoirConstraintInner as "operations_research::sat::ReservoirConstraint"
+);
+
+impl<'model> ReservoirConstraint<'model> {
+    fn from_inner(inner: ReservoirConstraintInner) -> Self {
+        Self {
+            inner,
+            _model: PhantomData,
+        }
+    }
+
+    /// Adds a mandatory event
+    ///
+    /// It will increase the used capacity by `level_change` at time `time`.
+    /// `time` must be an affine expression.
+    pub fn add_event(&mut self, time: impl Into<LinearExpr>, level_change: i64) {
+        let inner = &mut self.inner;
+
+        let time = time.into();
+        let time = &time;
+
+        unsafe {
+            cpp!([
+                inner as "operations_research::sat::ReservoirConstraint*",
+                time as "const operations_research::sat::LinearExpr*",
+                level_change as "int64_t"
+            ]
+                {
+                    inner->AddEvent(*time, level_change);
+                }
+            )
+        }
+    }
+
+    /// Adds an optional event
+    ///
+    /// If `is_active` is true, It will increase the used capacity by
+    /// `level_change` at time `time`. `time` must be an affine expression.
+    pub fn add_optional_event(
+        &mut self,
+        time: impl Into<LinearExpr>,
+        level_change: i64,
+        is_active: BoolVar<'model>,
+    ) {
+        let inner = &mut self.inner;
+
+        let time = time.into();
+        let time = &time;
+        let is_active = &is_active.inner;
+
+        unsafe {
+            cpp!([
+                inner as "operations_research::sat::ReservoirConstraint*",
+                time as "const operations_research::sat::LinearExpr*",
+                level_change as "int64_t",
+                is_active as "const operations_research::sat::BoolVar*"
+            ]
+                {
+                    inner->AddOptionalEvent(*time, level_change, *is_active);
+                }
+            )
+        }
+    }
+}
+
+impl_constraint_deref!(ReservoirConstraint);
+
+/// Specialized automaton constraint.
+///
+/// This constraint allows adding transitions to the automaton constraint
+/// incrementally.
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct AutomatonConstraint<'model> {
+    /// Original constraint
+    inner: AutomatonConstraintInner,
+    /// Lifetime limiter for model
+    _model: PhantomData<&'model CpModelBuilder>,
+}
+
+cpp_class!(
+    #[doc(hidden)]
+    unsafe struct AutomatonConstraintInner as "operations_research::sat::AutomatonConstraint"
+);
+
+impl<'model> AutomatonConstraint<'model> {
+    fn from_inner(inner: AutomatonConstraintInner) -> Self {
+        Self {
+            inner,
+            _model: PhantomData,
+        }
+    }
+
+    /// Adds a transitions to the automaton.
+    pub fn add_transition(&mut self, tail: c_int, head: c_int, transition_label: i64) {
+        let inner = &mut self.inner;
+
+        unsafe {
+            cpp!([
+                inner as "operations_research::sat::AutomatonConstraint*",
+                tail as "int",
+                head as "int",
+                transition_label as "int64_t"
+            ]
+                {
+                    inner->AddTransition(tail, head, transition_label);
+                }
+            )
+        }
+    }
+}
+
+impl_constraint_deref!(AutomatonConstraint);
+
+/// Specialized no_overlap2D constraint.
+///
+/// This constraint allows adding rectangles to the no_overlap2D
+/// constraint incrementally.
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct NoOverlap2DConstraint<'model> {
+    /// Original constraint
+    inner: NoOverlap2DConstraintInner,
+    /// Lifetime limiter for model
+    _model: PhantomData<&'model CpModelBuilder>,
+}
+
+cpp_class!(
+    #[doc(hidden)]
+    unsafe struct NoOverlap2DConstraintInner as "operations_research::sat::NoOverlap2DConstraint"
+);
+
+impl<'model> NoOverlap2DConstraint<'model> {
+    fn from_inner(inner: NoOverlap2DConstraintInner) -> Self {
+        Self {
+            inner,
+            _model: PhantomData,
+        }
+    }
+
+    /// Adds a rectangle (parallel to the axis) to the constraint.
+    pub fn add_rectangle(
+        &mut self,
+        x_coordinate: IntervalVar<'model>,
+        y_coordinate: IntervalVar<'model>,
+    ) {
+        let inner = &mut self.inner;
+
+        let x_coordinate = &x_coordinate.inner;
+        let y_coordinate = &y_coordinate.inner;
+
+        unsafe {
+            cpp!([
+                inner as "operations_research::sat::NoOverlap2DConstraint*",
+                x_coordinate as "const operations_research::sat::IntervalVar*",
+                y_coordinate as "const operations_research::sat::IntervalVar*"
+            ]
+                {
+                    inner->AddRectangle(*x_coordinate, *y_coordinate);
+                }
+            )
+        }
+    }
+}
+
+impl_constraint_deref!(NoOverlap2DConstraint);
+
+/// Specialized cumulative constraint.
+///
+/// This constraint allows adding fixed or variables demands to the cumulative
+/// constraint incrementally.
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct CumulativeConstraint<'model> {
+    /// Original constraint
+    inner: CumulativeConstraintInner,
+    /// Lifetime limiter for model
+    _model: PhantomData<&'model CpModelBuilder>,
+}
+
+cpp_class!(
+    #[doc(hidden)]
+    unsafe struct CumulativeConstraintInner as "operations_research::sat::CumulativeConstraint"
+);
+
+impl<'model> CumulativeConstraint<'model> {
+    fn from_inner(inner: CumulativeConstraintInner) -> Self {
+        Self {
+            inner,
+            _model: PhantomData,
+        }
+    }
+
+    /// Adds a pair (interval, demand) to the constraint.
+    pub fn add_demand(&mut self, interval: IntervalVar<'model>, demand: impl Into<LinearExpr>) {
+        let inner = &mut self.inner;
+
+        let interval = &interval.inner;
+        let demand = demand.into();
+        let demand = &demand;
+
+        unsafe {
+            cpp!([
+                inner as "operations_research::sat::CumulativeConstraint*",
+                interval as "const operations_research::sat::IntervalVar*",
+                demand as "const operations_research::sat::LinearExpr*"
+            ]
+                {
+                    inner->AddDemand(*interval, *demand);
+                }
+            )
+        }
+    }
+}
+
+impl_constraint_deref!(CumulativeConstraint);
+
+/// A soft constraint, whose violation is penalized in the objective.
+///
+/// This can only be constructed via `CpModelBuilder::add_soft_linear()` and
+/// the other `CpModelBuilder::add_soft_xxx()` methods.
+#[derive(Clone)]
+pub struct SoftConstraint<'model> {
+    satisfied: BoolVar<'model>,
+    violation: LinearExpr,
+    penalty: i64,
+}
+
+impl<'model> SoftConstraint<'model> {
+    /// Sets the name of the literal of the soft constraint, e.g. to identify
+    /// it in reports.
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.satisfied = self.satisfied.with_name(name);
+        self
+    }
+
+    /// Returns the name of the soft constraint.
+    pub fn name(&self) -> String {
+        self.satisfied.name()
+    }
+
+    /// Returns the literal that is true if and only if the constraint is
+    /// satisfied.
+    pub fn satisfied_literal(&self) -> BoolVar<'model> {
+        self.satisfied
+    }
+
+    /// Returns the expression of the violation, which is 0 when the
+    /// constraint is satisfied.
+    pub fn violation_expr(&self) -> &LinearExpr {
+        &self.violation
+    }
+
+    /// Returns the penalty of each unit of violation.
+    pub fn penalty(&self) -> i64 {
+        self.penalty
+    }
+
+    /// Returns whether the constraint is violated in the given solution.
+    pub fn is_violated(&self, response: &CpSolverResponse) -> bool {
+        !response.boolean_value(&self.satisfied)
+    }
+
+    /// Returns by how much the constraint is violated in the given solution.
+    pub fn violation(&self, response: &CpSolverResponse) -> i64 {
+        response.evaluate(&self.violation)
+    }
+
+    /// Returns the penalty paid for the constraint in the given solution.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cost overflows an `i64`.
+    pub fn cost(&self, response: &CpSolverResponse) -> i64 {
+        self.violation(response)
+            .checked_mul(self.penalty)
+            .expect("the cost of the soft constraint overflows")
+    }
+}
+
+cpp_class!(
+    /// A dedicated container for linear expressions.
+    ///
+    /// With the use of implicit conversions and operator overloading, it can
+    /// be used to represent a linear expression of integer variables and
+    /// constants:
+    ///
+    /// ```ignore
+    /// let model = CpModelBuilder::new();
+    /// let x = model.new_int_var(0..=10);
+    /// let y = model.new_int_var(0..=10);
+    /// let b = model.new_bool_var();
+    /// let expr = 2 * x + y - 3 + b;
+    /// model.add_linear_le(expr, 10);
+    /// ```
+    ///
+    /// This can also be built from an iterator of terms with `Sum`, or with
+    /// `LinearExpr::sum()` and `LinearExpr::weighted_sum()`.
+    pub unsafe struct LinearExpr as "operations_research::sat::LinearExpr"
+);
+
+impl LinearExpr {
+    /// Constructs the sum of a list of variables.
+    pub fn sum(vars: &[IntVar<'_>]) -> Self {
+        let vars_ptr = vars.as_ptr();
+        let vars_len = vars.len();
+
+        unsafe {
+            cpp!([
+                vars_ptr as "const operations_research::sat::IntVar*",
+                vars_len as "size_t"
+            ] -> LinearExpr as "operations_research::sat::LinearExpr"
+                {
+                    return operations_research::sat::LinearExpr::Sum(
+                        absl::Span<const operations_research::sat::IntVar>(vars_ptr, vars_len)
+                    );
+                }
+            )
+        }
+    }
+
+    /// Constructs the scalar product of variables and coefficients.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `vars` and `coeffs` do not have the same length.
+    pub fn weighted_sum(vars: &[IntVar<'_>], coeffs: &[i64]) -> Self {
+        assert_eq!(
+            vars.len(),
+            coeffs.len(),
+            "variables and coefficients should have the same length",
+        );
+
+        let vars_ptr = vars.as_ptr();
+        let coeffs_ptr = coeffs.as_ptr();
+        let len = vars.len();
+
+        unsafe {
+            cpp!([
+                vars_ptr as "const operations_research::sat::IntVar*",
+                coeffs_ptr as "const int64_t*",
+                len as "size_t"
+            ] -> LinearExpr as "operations_research::sat::LinearExpr"
+                {
+                    return operations_research::sat::LinearExpr::WeightedSum(
+                        absl::Span<const operations_research::sat::IntVar>(vars_ptr, len),
+                        absl::Span<const int64_t>(coeffs_ptr, len)
+                    );
+                }
+            )
+        }
+    }
+
+    /// Constructs `var * coefficient`.
+    pub fn term(var: IntVar<'_>, coefficient: i64) -> Self {
+        let var = &var.inner;
+
+        unsafe {
+            cpp!([
+                var as "const operations_research::sat::IntVar*",
+                coefficient as "int64_t"
+            ] -> LinearExpr as "operations_research::sat::LinearExpr"
+                {
+                    return operations_research::sat::LinearExpr::Term(*var, coefficient);
+                }
+            )
+        }
+    }
+
+    /// Returns the vector of variable indices.
+    pub fn variables(&self) -> &[c_int] {
+        let len = self.len();
+        if len == 0 {
+            return &[];
+        }
+
+        let data = unsafe {
+            cpp!([
+                self as "const operations_research::sat::LinearExpr*"
+            ] -> *const c_int as "const int*"
+                {
+                    return self->variables().data();
+                }
+            )
+        };
+
+        unsafe { slice::from_raw_parts(data, len) }
+    }
+
+    /// Returns the vector of coefficients.
+    pub fn coefficients(&self) -> &[i64] {
+        let len = self.len();
+        if len == 0 {
+            return &[];
+        }
+
+        let data = unsafe {
+            cpp!([
+                self as "const operations_research::sat::LinearExpr*"
+            ] -> *const i64 as "const int64_t*"
+                {
+                    return self->coefficients().data();
+                }
+            )
+        };
+
+        unsafe { slice::from_raw_parts(data, len) }
+    }
+
+    /// Returns the constant term.
+    pub fn constant(&self) -> i64 {
+        unsafe {
+            cpp!([
+                self as "const operations_research::sat::LinearExpr*"
+            ] -> i64 as "int64_t"
+                {
+                    return self->constant();
+                }
+            )
+        }
+    }
+
+    /// Returns true if the expression has no variables.
+    pub fn is_constant(&self) -> bool {
+        unsafe {
+            cpp!([
+                self as "const operations_research::sat::LinearExpr*"
+            ] -> bool as "bool"
+                {
+                    return self->IsConstant();
+                }
+            )
+        }
+    }
+
+    fn len(&self) -> usize {
+        unsafe {
+            cpp!([
+                self as "const operations_research::sat::LinearExpr*"
+            ] -> usize as "size_t"
+                {
+                    return self->variables().size();
+                }
+            )
+        }
+    }
+}
+
+impl fmt::Debug for LinearExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let repr = unsafe {
+            cpp!([
+                self as "const operations_research::sat::LinearExpr*"
+            ] -> CxxString as "std::unique_ptr<std::string>"
+                {
+                    return std::make_unique<std::string>(self->DebugString());
+                }
+            )
+        };
+
+        write!(f, "{repr}")
+    }
+}
+
+impl<T> From<T> for LinearExpr
+where
+    T: self::wrap::Expr,
+{
+    fn from(value: T) -> Self {
+        value.into_linear_expr()
+    }
+}
+
+impl self::wrap::Expr for &LinearExpr {
+    fn into_linear_expr(self) -> LinearExpr {
+        self.clone()
+    }
+}
+
+impl self::wrap::Expr for i64 {
+    fn into_linear_expr(self) -> LinearExpr {
+        let constant = self;
+
+        unsafe {
+            cpp!([
+                constant as "int64_t"
+            ] -> LinearExpr as "operations_research::sat::LinearExpr"
+                {
+                    return operations_research::sat::LinearExpr(constant);
+                }
+            )
+        }
+    }
+}
+
+impl self::wrap::Expr for IntVar<'_> {
+    fn into_linear_expr(self) -> LinearExpr {
+        let var = &self.inner;
+
+        unsafe {
+            cpp!([
+                var as "const operations_research::sat::IntVar*"
+            ] -> LinearExpr as "operations_research::sat::LinearExpr"
+                {
+                    return operations_research::sat::LinearExpr(*var);
+                }
+            )
+        }
+    }
+}
+
+impl self::wrap::Expr for BoolVar<'_> {
+    fn into_linear_expr(self) -> LinearExpr {
+        let var = &self.inner;
+
+        unsafe {
+            cpp!([
+                var as "const operations_research::sat::BoolVar*"
+            ] -> LinearExpr as "operations_research::sat::LinearExpr"
+                {
+                    return operations_research::sat::LinearExpr(*var);
+                }
+            )
+        }
+    }
+}
+
+impl<T> AddAssign<T> for LinearExpr
+where
+    T: Into<LinearExpr>,
+{
+    fn add_assign(&mut self, rhs: T) {
+        let other = rhs.into();
+        let other = &other;
+
+        unsafe {
+            cpp!([
+                self as "operations_research::sat::LinearExpr*",
+                other as "const operations_research::sat::LinearExpr*"
+            ]
+                {
+                    *self += *other;
+                }
+            )
+        }
+    }
+}
+
+impl<T> SubAssign<T> for LinearExpr
+where
+    T: Into<LinearExpr>,
+{
+    fn sub_assign(&mut self, rhs: T) {
+        let other = rhs.into();
+        let other = &other;
+
+        unsafe {
+            cpp!([
+                self as "operations_research::sat::LinearExpr*",
+                other as "const operations_research::sat::LinearExpr*"
+            ]
+                {
+                    *self -= *other;
+                }
+            )
+        }
+    }
+}
+
+impl MulAssign<i64> for LinearExpr {
+    fn mul_assign(&mut self, rhs: i64) {
+        let factor = rhs;
+
+        unsafe {
+            cpp!([
+                self as "operations_research::sat::LinearExpr*",
+                factor as "int64_t"
+            ]
+                {
+                    *self *= factor;
+                }
+            )
+        }
+    }
+}
+
+impl<T> Add<T> for LinearExpr
+where
+    T: Into<LinearExpr>,
+{
+    type Output = LinearExpr;
+
+    fn add(mut self, rhs: T) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<T> Sub<T> for LinearExpr
+where
+    T: Into<LinearExpr>,
+{
+    type Output = LinearExpr;
+
+    fn sub(mut self, rhs: T) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl Mul<i64> for LinearExpr {
+    type Output = LinearExpr;
+
+    fn mul(mut self, rhs: i64) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+
+impl Neg for LinearExpr {
+    type Output = LinearExpr;
+
+    fn neg(self) -> Self::Output {
+        self * -1
+    }
+}
+
+impl<T> Sum<T> for LinearExpr
+where
+    T: Into<LinearExpr>,
+{
+    fn sum<I: Iterator<Item = T>>(iter: I) -> Self {
+        iter.fold(Self::default(), |sum, expr| sum + expr)
+    }
+}
+
+macro_rules! impl_linear_ops {
+    ($ty:ident) => {
+        impl<'model, T> Add<T> for $ty<'model>
+        where
+            T: Into<LinearExpr>,
+        {
+            type Output = LinearExpr;
+
+            fn add(self, rhs: T) -> Self::Output {
+                LinearExpr::from(self) + rhs
+            }
+        }
+
+        impl<'model, T> Sub<T> for $ty<'model>
+        where
+            T: Into<LinearExpr>,
+        {
+            type Output = LinearExpr;
+
+            fn sub(self, rhs: T) -> Self::Output {
+                LinearExpr::from(self) - rhs
+            }
+        }
+
+        impl<'model> Mul<i64> for $ty<'model> {
+            type Output = LinearExpr;
+
+            fn mul(self, rhs: i64) -> Self::Output {
+                LinearExpr::from(self) * rhs
+            }
+        }
+
+        impl<'model> Mul<f64> for $ty<'model> {
+            type Output = DoubleLinearExpr;
+
+            fn mul(self, rhs: f64) -> Self::Output {
+                DoubleLinearExpr::from(self) * rhs
+            }
+        }
+
+        impl<'model> Neg for $ty<'model> {
+            type Output = LinearExpr;
+
+            fn neg(self) -> Self::Output {
+                -LinearExpr::from(self)
+            }
+        }
+
+        impl<'model> Add<$ty<'model>> for i64 {
+            type Output = LinearExpr;
+
+            fn add(self, rhs: $ty<'model>) -> Self::Output {
+                LinearExpr::from(rhs) + self
+            }
+        }
+
+        impl<'model> Sub<$ty<'model>> for i64 {
+            type Output = LinearExpr;
+
+            fn sub(self, rhs: $ty<'model>) -> Self::Output {
+                -LinearExpr::from(rhs) + self
+            }
+        }
+
+        impl<'model> Mul<$ty<'model>> for i64 {
+            type Output = LinearExpr;
+
+            fn mul(self, rhs: $ty<'model>) -> Self::Output {
+                LinearExpr::from(rhs) * self
+            }
+        }
+
+        impl<'model> Mul<$ty<'model>> for f64 {
+            type Output = DoubleLinearExpr;
+
+            fn mul(self, rhs: $ty<'model>) -> Self::Output {
+                DoubleLinearExpr::from(rhs) * self
+            }
+        }
+    };
+}
+
+impl_linear_ops!(IntVar);
+impl_linear_ops!(BoolVar);
+
+impl Add<LinearExpr> for i64 {
+    type Output = LinearExpr;
+
+    fn add(self, rhs: LinearExpr) -> Self::Output {
+        rhs + self
+    }
+}
+
+impl Sub<LinearExpr> for i64 {
+    type Output = LinearExpr;
+
+    fn sub(self, rhs: LinearExpr) -> Self::Output {
+        -rhs + self
+    }
+}
+
+impl Mul<LinearExpr> for i64 {
+    type Output = LinearExpr;
+
+    fn mul(self, rhs: LinearExpr) -> Self::Output {
+        rhs * self
+    }
+}
+
+cpp_class!(
+    /// A dedicated container for linear expressions with double coefficients.
+    /// This is currently only usable to define a floating point objective.
+    ///
+    /// Integer variables and linear expressions can be scaled by `f64`
+    /// constants, and are then summed like `LinearExpr`:
+    ///
+    /// ```ignore
+    /// let expr = 0.5 * x + 1.5 * y - 2.0;
+    /// ```
+    pub unsafe struct DoubleLinearExpr as "operations_research::sat::DoubleLinearExpr"
+);
+
+impl DoubleLinearExpr {
+    /// Constructs the scalar product of variables and coefficients.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `vars` and `coeffs` do not have the same length.
+    pub fn weighted_sum(vars: &[IntVar<'_>], coeffs: &[f64]) -> Self {
+        assert_eq!(
+            vars.len(),
+            coeffs.len(),
+            "variables and coefficients should have the same length",
+        );
+
+        let vars_ptr = vars.as_ptr();
+        let coeffs_ptr = coeffs.as_ptr();
+        let len = vars.len();
+
+        unsafe {
+            cpp!([
+                vars_ptr as "const operations_research::sat::IntVar*",
+                coeffs_ptr as "const double*",
+                len as "size_t"
+            ] -> DoubleLinearExpr as "operations_research::sat::DoubleLinearExpr"
+                {
+                    return operations_research::sat::DoubleLinearExpr::WeightedSum(
+                        absl::Span<const operations_research::sat::IntVar>(vars_ptr, len),
+                        absl::Span<const double>(coeffs_ptr, len)
+                    );
+                }
+            )
+        }
+    }
+
+    /// Returns the vector of variable indices.
+    pub fn variables(&self) -> &[c_int] {
+        let len = self.len();
+        if len == 0 {
+            return &[];
+        }
+
+        let data = unsafe {
+            cpp!([
+                self as "const operations_research::sat::DoubleLinearExpr*"
+            ] -> *const c_int as "const int*"
+                {
+                    return self->variables().data();
+                }
+            )
+        };
+
+        unsafe { slice::from_raw_parts(data, len) }
+    }
+
+    /// Returns the vector of coefficients.
+    pub fn coefficients(&self) -> &[f64] {
+        let len = self.len();
+        if len == 0 {
+            return &[];
+        }
+
+        let data = unsafe {
+            cpp!([
+                self as "const operations_research::sat::DoubleLinearExpr*"
+            ] -> *const f64 as "const double*"
+                {
+                    return self->coefficients().data();
+                }
+            )
+        };
+
+        unsafe { slice::from_raw_parts(data, len) }
+    }
+
+    /// Returns the constant term.
+    pub fn constant(&self) -> f64 {
+        unsafe {
+            cpp!([
+                self as "const operations_research::sat::DoubleLinearExpr*"
+            ] -> f64 as "double"
+                {
+                    return self->constant();
+                }
+            )
+        }
+    }
+
+    /// Returns true if the expression has no variables.
+    pub fn is_constant(&self) -> bool {
+        unsafe {
+            cpp!([
+                self as "const operations_research::sat::DoubleLinearExpr*"
+            ] -> bool as "bool"
+                {
+                    return self->IsConstant();
+                }
+            )
+        }
+    }
+
+    fn len(&self) -> usize {
+        unsafe {
+            cpp!([
+                self as "const operations_research::sat::DoubleLinearExpr*"
+            ] -> usize as "size_t"
+                {
+                    return self->variables().size();
+                }
+            )
+        }
+    }
+}
+
+impl fmt::Debug for DoubleLinearExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let repr = unsafe {
+            cpp!([
+                self as "const operations_research::sat::DoubleLinearExpr*"
+            ] -> CxxString as "std::unique_ptr<std::string>"
+                {
+                    return std::make_unique<std::string>(self->DebugString());
+                }
+            )
+        };
+
+        write!(f, "{repr}")
+    }
+}
+
+impl<T> From<T> for DoubleLinearExpr
+where
+    T: self::wrap::DoubleExpr,
+{
+    fn from(value: T) -> Self {
+        value.into_double_linear_expr()
+    }
+}
+
+impl self::wrap::DoubleExpr for &DoubleLinearExpr {
+    fn into_double_linear_expr(self) -> DoubleLinearExpr {
+        self.clone()
+    }
+}
+
+impl self::wrap::DoubleExpr for f64 {
+    fn into_double_linear_expr(self) -> DoubleLinearExpr {
+        let constant = self;
+
+        unsafe {
+            cpp!([
+                constant as "double"
+            ] -> DoubleLinearExpr as "operations_research::sat::DoubleLinearExpr"
+                {
+                    return operations_research::sat::DoubleLinearExpr(constant);
+                }
+            )
+        }
+    }
+}
+
+impl self::wrap::DoubleExpr for IntVar<'_> {
+    fn into_double_linear_expr(self) -> DoubleLinearExpr {
+        let var = &self.inner;
+
+        unsafe {
+            cpp!([
+                var as "const operations_research::sat::IntVar*"
+            ] -> DoubleLinearExpr as "operations_research::sat::DoubleLinearExpr"
+                {
+                    return operations_research::sat::DoubleLinearExpr(*var);
+                }
+            )
+        }
+    }
+}
+
+impl self::wrap::DoubleExpr for BoolVar<'_> {
+    fn into_double_linear_expr(self) -> DoubleLinearExpr {
+        let var = &self.inner;
+
+        unsafe {
+            cpp!([
+                var as "const operations_research::sat::BoolVar*"
+            ] -> DoubleLinearExpr as "operations_research::sat::DoubleLinearExpr"
+                {
+                    return operations_research::sat::DoubleLinearExpr(*var);
+                }
+            )
+        }
+    }
+}
+
+impl self::wrap::DoubleExpr for LinearExpr {
+    fn into_double_linear_expr(self) -> DoubleLinearExpr {
+        let expr = &self;
+
+        unsafe {
+            cpp!([
+                expr as "const operations_research::sat::LinearExpr*"
+            ] -> DoubleLinearExpr as "operations_research::sat::DoubleLinearExpr"
+                {
+                    operations_research::sat::DoubleLinearExpr result;
+                    result.AddExpression(*expr);
+                    return result;
+                }
+            )
+        }
+    }
+}
+
+impl<T> AddAssign<T> for DoubleLinearExpr
+where
+    T: Into<DoubleLinearExpr>,
+{
+    fn add_assign(&mut self, rhs: T) {
+        let other = rhs.into();
+        let other = &other;
+
+        unsafe {
+            cpp!([
+                self as "operations_research::sat::DoubleLinearExpr*",
+                other as "const operations_research::sat::DoubleLinearExpr*"
+            ]
+                {
+                    *self += *other;
+                }
+            )
+        }
+    }
+}
+
+impl<T> SubAssign<T> for DoubleLinearExpr
+where
+    T: Into<DoubleLinearExpr>,
+{
+    fn sub_assign(&mut self, rhs: T) {
+        let other = rhs.into();
+        let other = &other;
+
+        unsafe {
+            cpp!([
+                self as "operations_research::sat::DoubleLinearExpr*",
+                other as "const operations_research::sat::DoubleLinearExpr*"
+            ]
+                {
+                    *self -= *other;
+                }
+            )
+        }
+    }
+}
+
+impl MulAssign<f64> for DoubleLinearExpr {
+    fn mul_assign(&mut self, rhs: f64) {
+        let coeff = rhs;
+
+        unsafe {
+            cpp!([
+                self as "operations_research::sat::DoubleLinearExpr*",
+                coeff as "double"
+            ]
+                {
+                    *self *= coeff;
+                }
+            )
+        }
+    }
+}
+
+impl<T> Add<T> for DoubleLinearExpr
+where
+    T: Into<DoubleLinearExpr>,
+{
+    type Output = DoubleLinearExpr;
+
+    fn add(mut self, rhs: T) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<T> Sub<T> for DoubleLinearExpr
+where
+    T: Into<DoubleLinearExpr>,
+{
+    type Output = DoubleLinearExpr;
+
+    fn sub(mut self, rhs: T) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl Mul<f64> for DoubleLinearExpr {
+    type Output = DoubleLinearExpr;
+
+    fn mul(mut self, rhs: f64) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+
+impl Mul<DoubleLinearExpr> for f64 {
+    type Output = DoubleLinearExpr;
+
+    fn mul(self, rhs: DoubleLinearExpr) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl Mul<LinearExpr> for f64 {
+    type Output = DoubleLinearExpr;
+
+    fn mul(self, rhs: LinearExpr) -> Self::Output {
+        DoubleLinearExpr::from(rhs) * self
+    }
+}
+
+impl Neg for DoubleLinearExpr {
+    type Output = DoubleLinearExpr;
+
+    fn neg(self) -> Self::Output {
+        self * -1.0
+    }
+}
+
+impl<T> Sum<T> for DoubleLinearExpr
+where
+    T: Into<DoubleLinearExpr>,
+{
+    fn sum<I: Iterator<Item = T>>(iter: I) -> Self {
+        iter.fold(Self::default(), |sum, expr| sum + expr)
+    }
+}
+
+cpp_class!(
+    /// The response of a single call to the solver.
+    pub unsafe struct CpSolverResponse as "operations_research::sat::CpSolverResponse"
+);
+
+impl CpSolverResponse {
+    /// Creates a response from a serialized CpSolverResponse, as produced by
+    /// `export_to_bytes()`.
+    ///
+    /// Returns `None` if the bytes could not be parsed.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut response = unsafe {
+            cpp!([
+            ] -> CpSolverResponse as "operations_research::sat::CpSolverResponse"
+                {
+                    return operations_research::sat::CpSolverResponse();
+                }
+            )
+        };
+        let response_ref = &mut response;
+
+        let bytes_ptr = bytes.as_ptr();
+        let bytes_len = bytes.len();
+
+        let parsed = unsafe {
+            cpp!([
+                response_ref as "operations_research::sat::CpSolverResponse*",
+                bytes_ptr as "const void*",
+                bytes_len as "size_t"
+            ] -> bool as "bool"
+                {
+                    return response_ref->ParseFromArray(bytes_ptr, static_cast<int>(bytes_len));
+                }
+            )
+        };
+        parsed.then_some(response)
+    }
+
+    /// Creates a response from the pure-Rust CpSolverResponse, as produced by
+    /// `export_to_proto()`.
+    ///
+    /// Returns `None` if the native library rejects the message.
+    #[cfg(feature = "proto")]
+    pub fn from_proto(proto: &proto::CpSolverResponse) -> Option<Self> {
+        Self::from_bytes(&proto.encode_to_vec())
+    }
+
+    /// Returns the serialized CpSolverResponse.
+    pub fn export_to_bytes(&self) -> Vec<u8> {
+        unsafe {
+            cpp!([
+                self as "const operations_research::sat::CpSolverResponse*"
+            ] -> CxxString as "std::unique_ptr<std::string>"
+                {
+                    return std::make_unique<std::string>(self->SerializeAsString());
+                }
+            )
+        }
+        .as_bytes()
+        .to_vec()
+    }
+
+    /// Returns the response as a pure-Rust message.
+    #[cfg(feature = "proto")]
+    pub fn export_to_proto(&self) -> proto::CpSolverResponse {
+        proto::CpSolverResponse::decode(self.export_to_bytes().as_slice())
+            .expect("the native library should serialize a valid CpSolverResponse")
+    }
+
+    /// The status of the solve.
+    pub fn status(&self) -> CpSolverStatus {
+        let status = unsafe {
+            cpp!([
+                self as "const operations_research::sat::CpSolverResponse*"
+            ] -> u8 as "uint8_t"
+                {
+                    switch (self->status()) {
+                        case operations_research::sat::CpSolverStatus::UNKNOWN:
+                            return 0;
+                        case operations_research::sat::CpSolverStatus::MODEL_INVALID:
+                            return 1;
+                        case operations_research::sat::CpSolverStatus::FEASIBLE:
+                            return 2;
+                        case operations_research::sat::CpSolverStatus::INFEASIBLE:
+                            return 3;
+                        case operations_research::sat::CpSolverStatus::OPTIMAL:
+                            return 4;
+                        default:
+                            return 0;
+                    }
+                }
+            )
+        };
+
+        match status {
+            0 => CpSolverStatus::Unknown,
+            1 => CpSolverStatus::ModelInvalid,
+            2 => CpSolverStatus::Feasible,
+            3 => CpSolverStatus::Infeasible,
+            4 => CpSolverStatus::Optimal,
+            5.. => unreachable!(),
+        }
+    }
+
+    /// A feasible solution to the given problem. Depending on the returned status
+    /// it may be optimal or just feasible. This is in one-to-one correspondence
+    /// with a CpModelProto::variables repeated field and list the values of all
+    /// the variables.
+    pub fn solution(&self) -> &[i64] {
+        let len = unsafe {
+            cpp!([
+                self as "const operations_research::sat::CpSolverResponse*"
+            ] -> usize as "size_t"
+                {
+                    return self->solution_size();
+                }
+            )
+        };
+        if len == 0 {
+            return &[];
+        }
+
+        let ptr = unsafe {
+            cpp!([
+                self as "const operations_research::sat::CpSolverResponse*"
+            ] -> *const i64 as "const int64_t*"
+                {
+                    return self->solution().data();
+                }
+            )
+        };
+        unsafe { slice::from_raw_parts(ptr, len) }
+    }
+
+    /// Only make sense for an optimization problem. The objective value to be
+    /// minimized or maximized, using the same scaling as the objective of the
+    /// model.
+    pub fn objective_value(&self) -> f64 {
+        unsafe {
+            cpp!([
+                self as "const operations_research::sat::CpSolverResponse*"
+            ] -> f64 as "double"
+                {
+                    return self->objective_value();
+                }
+            )
+        }
+    }
+
+    /// Only make sense for an optimization problem. A proven lower-bound on the
+    /// objective for a minimization problem, or a proven upper-bound for a
+    /// maximization problem.
+    pub fn best_objective_bound(&self) -> f64 {
+        unsafe {
+            cpp!([
+                self as "const operations_research::sat::CpSolverResponse*"
+            ] -> f64 as "double"
+                {
+                    return self->best_objective_bound();
+                }
+            )
+        }
+    }
+
+    /// The number of conflicts encountered during the search.
+    pub fn num_conflicts(&self) -> i64 {
+        unsafe {
+            cpp!([
+                self as "const operations_research::sat::CpSolverResponse*"
+            ] -> i64 as "int64_t"
+                {
+                    return self->num_conflicts();
+                }
+            )
+        }
+    }
+
+    /// The number of branches explored during the search.
+    pub fn num_branches(&self) -> i64 {
+        unsafe {
+            cpp!([
+                self as "const operations_research::sat::CpSolverResponse*"
+            ] -> i64 as "int64_t"
+                {
+                    return self->num_branches();
+                }
+            )
+        }
+    }
+
+    /// The wall time of the search, in seconds.
+    pub fn wall_time(&self) -> f64 {
+        unsafe {
+            cpp!([
+                self as "const operations_research::sat::CpSolverResponse*"
+            ] -> f64 as "double"
+                {
+                    return self->wall_time();
+                }
+            )
+        }
+    }
+
+    /// The user time of the search, in seconds.
+    pub fn user_time(&self) -> f64 {
+        unsafe {
+            cpp!([
+                self as "const operations_research::sat::CpSolverResponse*"
+            ] -> f64 as "double"
+                {
+                    return self->user_time();
+                }
+            )
+        }
+    }
+
+    /// The deterministic time of the search, in "deterministic seconds".
+    pub fn deterministic_time(&self) -> f64 {
+        unsafe {
+            cpp!([
+                self as "const operations_research::sat::CpSolverResponse*"
+            ] -> f64 as "double"
+                {
+                    return self->deterministic_time();
+                }
+            )
+        }
+    }
+
+    /// A subset of the model "assumptions" field. This will only be filled if the
+    /// status is INFEASIBLE. This subset of assumption will be enough to still get
+    /// an infeasible problem.
+    ///
+    /// This is related to what is called the irreducible inconsistent subsystem or
+    /// IIS. Except one is only concerned by the provided assumptions. This is also
+    /// similar to what is called an unsat core.
+    ///
+    /// The literals are mapped back to handles of the given `model`, which must be
+    /// the one that produced this response.
+    pub fn sufficient_assumptions_for_infeasibility<'model>(
+        &self,
+        model: &'model CpModelBuilder,
+    ) -> Vec<BoolVar<'model>> {
+        let len = unsafe {
+            cpp!([
+                self as "const operations_research::sat::CpSolverResponse*"
+            ] -> usize as "size_t"
+                {
+                    return self->sufficient_assumptions_for_infeasibility_size();
+                }
+            )
+        };
+
+        (0..len)
+            .map(|index| {
+                let literal = unsafe {
+                    cpp!([
+                        self as "const operations_research::sat::CpSolverResponse*",
+                        index as "size_t"
+                    ] -> c_int as "int"
+                        {
+                            return self->sufficient_assumptions_for_infeasibility(index);
+                        }
+                    )
+                };
+                model.bool_var_from_literal_index(literal)
+            })
+            .collect()
+    }
+
+    /// Returns a string with some statistics on the solver response.
+    pub fn response_stats(&self) -> String {
+        unsafe {
+            cpp!([
+                self as "const operations_research::sat::CpSolverResponse*"
+            ] -> CxxString as "std::unique_ptr<std::string>"
+                {
+                    return std::make_unique<std::string>(operations_research::sat::CpSolverResponseStats(*self));
+                }
+            )
+        }
+        .into()
+    }
+
+    /// Evaluates the value of an integer variable in the solver response.
+    pub fn value(&self, var: &IntVar<'_>) -> i64 {
+        self.evaluate(*var)
+    }
+
+    /// Evaluates the value of a linear expression in the solver response.
+    pub fn evaluate(&self, expr: impl Into<LinearExpr>) -> i64 {
+        let expr = expr.into();
+        let expr = &expr;
+
+        unsafe {
+            cpp!([
+                self as "const operations_research::sat::CpSolverResponse*",
+                expr as "const operations_research::sat::LinearExpr*"
+            ] -> i64 as "int64_t"
+                {
+                    return operations_research::sat::SolutionIntegerValue(*self, *expr);
+                }
+            )
+        }
+    }
+
+    /// Evaluates the value of a Boolean literal in the solver response.
+    pub fn boolean_value(&self, var: &BoolVar<'_>) -> bool {
+        let var = &var.inner;
+
+        unsafe {
+            cpp!([
+                self as "const operations_research::sat::CpSolverResponse*",
+                var as "const operations_research::sat::BoolVar*"
+            ] -> bool as "bool"
+                {
+                    return operations_research::sat::SolutionBooleanValue(*self, *var);
+                }
+            )
+        }
+    }
+}
+
+impl fmt::Debug for CpSolverResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let repr = unsafe {
+            cpp!([
+                self as "const operations_research::sat::CpSolverResponse*"
+            ] -> CxxString as "std::unique_ptr<std::string>"
+                {
+                    return std::make_unique<std::string>(self->DebugString());
+                }
+            )
+        };
+
+        write!(f, "{repr}")
+    }
+}
+
+/// An iterator over the solutions of a model, returned by
+/// `CpModelBuilder::solutions()`.
+///
+/// Dropping it stops the search and waits for the solver thread to finish.
+pub struct Solutions {
+    receiver: Option<mpsc::Receiver<SolutionSnapshot>>,
+    search: Option<thread::JoinHandle<CpSolverResponse>>,
+    response: Option<CpSolverResponse>,
+    stopped: Arc<AtomicBool>,
+}
+
+impl Solutions {
+    /// Returns the final response of the search, once the iterator is
+    /// exhausted.
+    ///
+    /// Its status is `Optimal` if all the solutions were enumerated,
+    /// `Infeasible` if there is none, `ModelInvalid` if the model is invalid or
+    /// has an objective, and `Feasible` or `Unknown` if a limit was reached.
+    pub fn response(&self) -> Option<&CpSolverResponse> {
+        self.response.as_ref()
+    }
+
+    /// Returns the final status of the search, once the iterator is exhausted.
+    pub fn status(&self) -> Option<CpSolverStatus> {
+        self.response().map(CpSolverResponse::status)
+    }
+
+    /// Waits for the solver thread, keeping its final response.
+    fn finish(&mut self) {
+        self.receiver.take();
+
+        if let Some(search) = self.search.take() {
+            match search.join() {
+                Ok(response) => self.response = Some(response),
+                Err(payload) => panic::resume_unwind(payload),
+            }
+        }
+    }
+}
+
+impl Iterator for Solutions {
+    type Item = SolutionSnapshot;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let solution = self.receiver.as_ref()?.recv().ok();
+        if solution.is_none() {
+            self.finish();
+        }
+        solution
+    }
+}
+
+impl Drop for Solutions {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::SeqCst);
+        // Unblocks the solver if it is waiting for a solution to be consumed.
+        self.receiver.take();
+
+        if let Some(search) = self.search.take() {
+            search.join().ok();
+        }
+    }
+}
+
+/// A feasible solution copied out of the solver during the search.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SolutionSnapshot {
+    values: Vec<i64>,
+    wall_time: f64,
+}
+
+impl SolutionSnapshot {
+    fn from_response(response: &CpSolverResponse) -> Self {
+        Self {
+            values: response.solution().to_vec(),
+            wall_time: response.wall_time(),
+        }
+    }
+
+    /// The values of all the variables, indexed by `IntVar::index()`.
+    pub fn values(&self) -> &[i64] {
+        &self.values
+    }
+
+    /// The wall time of the search when the solution was found, in seconds.
+    pub fn wall_time(&self) -> f64 {
+        self.wall_time
+    }
+
+    /// Evaluates the value of an integer variable in the solution.
+    pub fn value(&self, var: &IntVar<'_>) -> i64 {
+        self.values[var.index() as usize]
+    }
+
+    /// Evaluates the value of a Boolean literal in the solution.
+    pub fn boolean_value(&self, var: &BoolVar<'_>) -> bool {
+        let index = var.index();
+        if index >= 0 {
+            self.values[index as usize] != 0
+        } else {
+            self.values[(-index - 1) as usize] == 0
+        }
+    }
+
+    /// Evaluates the value of a linear expression in the solution.
+    pub fn evaluate(&self, expr: impl Into<LinearExpr>) -> i64 {
+        let expr = expr.into();
+
+        expr.variables()
+            .iter()
+            .zip(expr.coefficients())
+            .map(|(&var, &coefficient)| coefficient * self.values[var as usize])
+            .sum::<i64>()
+            + expr.constant()
+    }
+}
+
+/// The status returned by a solver trying to solve a CpModelProto.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CpSolverStatus {
+    /// The status of the model is still unknown. A search limit has been reached
+    /// before any of the statuses below could be determined.
+    Unknown,
+    /// The given CpModelProto didn't pass the validation step.
+    ModelInvalid,
+    /// A feasible solution has been found. But the search was stopped before we
+    /// could prove optimality or before we enumerated all solutions of a
+    /// feasibility problem (if asked).
+    Feasible,
+    /// The problem has been proven infeasible.
+    Infeasible,
+    /// An optimal feasible solution has been found.
+    ///
+    /// More generally, this status represent a success. So we also return OPTIMAL
+    /// if we find a solution for a pure feasibility problem or if a gap limit has
+    /// been specified and we return a solution within this limit. In the case
+    /// where we need to return all the feasible solution, this status will only be
+    /// returned if we enumerated all of them; If we stopped before, we will return
+    /// FEASIBLE.
+    Optimal,
+}
+
+/// The order in which the variables of a decision strategy are selected.
+///
+/// The variable selection strategy is applied to the expressions in the order
+/// they were given, and ties are broken by this order.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[repr(u32)]
+pub enum VariableSelectionStrategy {
+    /// Select the first unassigned variable.
+    ChooseFirst = 0,
+    /// Select the variable with the lowest minimum value of its domain.
+    ChooseLowestMin = 1,
+    /// Select the variable with the highest maximum value of its domain.
+    ChooseHighestMax = 2,
+    /// Select the variable with the smallest domain size.
+    ChooseMinDomainSize = 3,
+    /// Select the variable with the largest domain size.
+    ChooseMaxDomainSize = 4,
+}
+
+/// The value assigned to the selected variable of a decision strategy.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[repr(u32)]
+pub enum DomainReductionStrategy {
+    /// Try to assign the minimum value of the domain first.
+    SelectMinValue = 0,
+    /// Try to assign the maximum value of the domain first.
+    SelectMaxValue = 1,
+    /// Try the lower half of the domain first.
+    SelectLowerHalf = 2,
+    /// Try the upper half of the domain first.
+    SelectUpperHalf = 3,
+    /// Try to assign the median value of the domain first.
+    SelectMedianValue = 4,
+}
@@ -0,0 +1,182 @@
+use super::{
+    cp_model::{CpModelBuilder, CpSolverResponse, CpSolverStatus, LinearExpr},
+    sat_parameters::SatParameters,
+};
+
+/// One objective of a lexicographic optimization, in order of priority.
+#[derive(Clone, Debug)]
+pub struct LexicographicObjective {
+    expr: LinearExpr,
+    maximize: bool,
+    tolerance: i64,
+}
+
+impl LexicographicObjective {
+    /// Creates an objective to minimize.
+    pub fn minimize(expr: impl Into<LinearExpr>) -> Self {
+        Self {
+            expr: expr.into(),
+            maximize: false,
+            tolerance: 0,
+        }
+    }
+
+    /// Creates an objective to maximize.
+    pub fn maximize(expr: impl Into<LinearExpr>) -> Self {
+        Self {
+            expr: expr.into(),
+            maximize: true,
+            tolerance: 0,
+        }
+    }
+
+    /// Sets how far from its best value the objective may degrade while the
+    /// next objectives are optimized. Defaults to 0, i.e. the objective stays
+    /// at its best value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tolerance` is negative.
+    pub fn with_tolerance(mut self, tolerance: i64) -> Self {
+        assert!(tolerance >= 0, "the tolerance must be non-negative");
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Returns the expression to optimize.
+    pub fn expr(&self) -> &LinearExpr {
+        &self.expr
+    }
+
+    /// Returns whether the objective is maximized.
+    pub fn is_maximize(&self) -> bool {
+        self.maximize
+    }
+
+    /// Returns the allowed degradation of the objective.
+    pub fn tolerance(&self) -> i64 {
+        self.tolerance
+    }
+}
+
+/// The result of a lexicographic optimization, with the response of each
+/// stage.
+#[derive(Debug)]
+pub struct LexicographicResponse {
+    stages: Vec<CpSolverResponse>,
+}
+
+impl LexicographicResponse {
+    /// Returns the responses of the stages that were run, in order. The stage
+    /// `i` optimizes the objective `i`; its status, objective value and timings
+    /// are the ones of that stage only.
+    ///
+    /// The stages stop at the first one without solution, so there can be
+    /// fewer stages than objectives.
+    pub fn stages(&self) -> &[CpSolverResponse] {
+        &self.stages
+    }
+
+    /// Returns the response of the last stage with a solution, which satisfies
+    /// all the previous objectives within their tolerance.
+    pub fn response(&self) -> Option<&CpSolverResponse> {
+        self.stages
+            .iter()
+            .rev()
+            .find(|response| has_solution(response))
+    }
+
+    /// Returns the overall status: `Optimal` if all the stages were solved to
+    /// optimality, `Feasible` if there is a solution, or else the status of the
+    /// last stage.
+    pub fn status(&self) -> CpSolverStatus {
+        match self.stages.last() {
+            Some(last) if has_solution(last) => {
+                if self
+                    .stages
+                    .iter()
+                    .all(|response| response.status() == CpSolverStatus::Optimal)
+                {
+                    CpSolverStatus::Optimal
+                } else {
+                    CpSolverStatus::Feasible
+                }
+            }
+            Some(last) if self.stages.len() > 1 => match last.status() {
+                // The previous stages found a solution, which the last one was
+                // not given the time to improve.
+                CpSolverStatus::Unknown => CpSolverStatus::Feasible,
+                status => status,
+            },
+            Some(last) => last.status(),
+            None => CpSolverStatus::Unknown,
+        }
+    }
+
+    /// Returns the total wall time of all the stages, in seconds.
+    pub fn wall_time(&self) -> f64 {
+        self.stages.iter().map(CpSolverResponse::wall_time).sum()
+    }
+}
+
+/// Optimizes the given objectives in lexicographic order.
+///
+/// The first objective is optimized alone. Then, its best value found is fixed
+/// as a constraint (up to the objective tolerance), the solution is given as a
+/// hint, and the next objective is optimized, and so on. The objective of the
+/// model, if any, is ignored, but the penalties of its soft constraints are
+/// added to each objective.
+///
+/// The stages run on a copy of the model, which is left untouched. Each of them
+/// uses the given parameters, so a time limit applies to each stage rather than
+/// to the whole optimization. Since the copy has the same variables, the
+/// responses can be read with the variables of the original model.
+pub fn solve_lexicographic(
+    model: &CpModelBuilder,
+    objectives: &[LexicographicObjective],
+    parameters: &SatParameters,
+) -> LexicographicResponse {
+    let model = model.copy();
+
+    let mut stages = Vec::with_capacity(objectives.len());
+    for (index, objective) in objectives.iter().enumerate() {
+        if index > 0 {
+            let previous: &CpSolverResponse = stages.last().unwrap();
+            model.hint_from_response(previous);
+        }
+        if objective.maximize {
+            model.maximize(objective.expr.clone());
+        } else {
+            model.minimize(objective.expr.clone());
+        }
+
+        let response = model.solve_with_parameters(parameters);
+        if !has_solution(&response) {
+            stages.push(response);
+            break;
+        }
+
+        let value = response.evaluate(&objective.expr);
+        if objective.maximize {
+            model.add_linear_ge(
+                objective.expr.clone(),
+                value.saturating_sub(objective.tolerance),
+            );
+        } else {
+            model.add_linear_le(
+                objective.expr.clone(),
+                value.saturating_add(objective.tolerance),
+            );
+        }
+        stages.push(response);
+    }
+
+    LexicographicResponse { stages }
+}
+
+fn has_solution(response: &CpSolverResponse) -> bool {
+    matches!(
+        response.status(),
+        CpSolverStatus::Optimal | CpSolverStatus::Feasible
+    )
+}
@@ -1 +1,6 @@
 pub mod cp_model;
+pub mod lexicographic;
+pub mod opb_reader;
+pub mod sat_cnf_reader;
+pub mod sat_parameters;
+pub mod sat_solver;
@@ -0,0 +1,634 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, Write},
+    path::Path,
+};
+
+use crate::utils::string::CxxString;
+
+use super::cp_model::{BoolVar, CpModelBuilder, LinearExpr};
+
+/// The variables are created as declared by the `* #variable= <n>` header or,
+/// without one, as their literals appear. This bounds their number, so that a
+/// single huge number cannot make the loader allocate that many variables.
+const MAX_NUM_VARIABLES: usize = 1 << 24;
+
+// IMPORT CXX LIBRARY
+cpp! {{
+    #include <algorithm>
+    #include <cstdint>
+    #include <limits>
+    #include <sstream>
+    #include <string>
+    #include <vector>
+
+    #include "ortools/sat/cp_model.pb.h"
+
+    namespace or_tools_rs {
+    namespace opb {
+
+    using operations_research::sat::ConstraintProto;
+    using operations_research::sat::CpModelProto;
+
+    int PositiveRef(int ref) { return ref >= 0 ? ref : -ref - 1; }
+
+    std::string Literal(int ref) {
+        return ref >= 0
+            ? "x" + std::to_string(ref + 1)
+            : "~x" + std::to_string(-ref);
+    }
+
+    // A CpModelProto seen as a WBO problem.
+    //
+    // A soft constraint has a single enforcement literal, whose negation is a
+    // variable only used to penalize the violation in the objective. The top
+    // cost is a linear constraint bounding the sum of these penalties.
+    struct WboModel {
+        const CpModelProto& model;
+        // The weight of each constraint, 0 for a hard one
+        std::vector<int64_t> weights;
+        // The objective coefficients of the variables, without the penalties
+        std::vector<int64_t> objective_coeffs;
+        // The index of the top cost constraint, or -1
+        int top_cost_constraint = -1;
+
+        explicit WboModel(const CpModelProto& model)
+            : model(model),
+              weights(model.constraints_size(), 0),
+              objective_coeffs(model.variables_size(), 0) {
+            for (int i = 0; i < model.objective().vars_size(); ++i) {
+                const int ref = model.objective().vars(i);
+                const int64_t coeff = model.objective().coeffs(i);
+                objective_coeffs[PositiveRef(ref)] += ref >= 0 ? coeff : -coeff;
+            }
+
+            std::vector<int> num_occurrences(model.variables_size(), 0);
+            for (const ConstraintProto& ct : model.constraints()) {
+                for (const int ref : ct.enforcement_literal()) {
+                    ++num_occurrences[PositiveRef(ref)];
+                }
+                for (const int ref : ct.bool_or().literals()) ++num_occurrences[PositiveRef(ref)];
+                for (const int ref : ct.bool_and().literals()) ++num_occurrences[PositiveRef(ref)];
+                for (const int ref : ct.at_most_one().literals()) ++num_occurrences[PositiveRef(ref)];
+                for (const int ref : ct.exactly_one().literals()) ++num_occurrences[PositiveRef(ref)];
+                for (const int ref : ct.linear().vars()) ++num_occurrences[PositiveRef(ref)];
+            }
+
+            // A violation literal may also appear in the top cost constraint.
+            std::vector<int64_t> candidates(model.variables_size(), 0);
+            int num_candidates = 0;
+            for (int c = 0; c < model.constraints_size(); ++c) {
+                const ConstraintProto& ct = model.constraints(c);
+                if (ct.enforcement_literal_size() != 1 || ct.enforcement_literal(0) >= 0) {
+                    continue;
+                }
+                const int violated = PositiveRef(ct.enforcement_literal(0));
+                if (objective_coeffs[violated] > 0 && candidates[violated] == 0
+                    && num_occurrences[violated] <= 2) {
+                    candidates[violated] = objective_coeffs[violated];
+                    ++num_candidates;
+                }
+            }
+
+            for (int c = 0; c < model.constraints_size() && num_candidates > 0; ++c) {
+                const ConstraintProto& ct = model.constraints(c);
+                if (ct.constraint_case() != ConstraintProto::kLinear
+                    || ct.enforcement_literal_size() > 0
+                    || ct.linear().vars_size() != num_candidates
+                    || ct.linear().domain_size() != 2
+                    || ct.linear().domain(0) != std::numeric_limits<int64_t>::min()) {
+                    continue;
+                }
+                bool is_top_cost = true;
+                for (int i = 0; i < ct.linear().vars_size(); ++i) {
+                    const int ref = ct.linear().vars(i);
+                    is_top_cost &= ref >= 0 && candidates[ref] > 0
+                        && candidates[ref] == ct.linear().coeffs(i);
+                }
+                if (is_top_cost) {
+                    top_cost_constraint = c;
+                    break;
+                }
+            }
+
+            for (int c = 0; c < model.constraints_size(); ++c) {
+                const ConstraintProto& ct = model.constraints(c);
+                if (ct.enforcement_literal_size() != 1 || ct.enforcement_literal(0) >= 0) {
+                    continue;
+                }
+                const int violated = PositiveRef(ct.enforcement_literal(0));
+                const int max_occurrences = top_cost_constraint >= 0 ? 2 : 1;
+                if (candidates[violated] > 0 && num_occurrences[violated] <= max_occurrences) {
+                    weights[c] = candidates[violated];
+                    objective_coeffs[violated] = 0;
+                    candidates[violated] = 0;
+                }
+            }
+        }
+
+        bool IsSoft() const {
+            return std::any_of(weights.begin(), weights.end(), [](int64_t w) { return w > 0; });
+        }
+
+        // Returns why the model cannot be written, or an empty string.
+        std::string Error() const {
+            for (int i = 0; i < model.variables_size(); ++i) {
+                const auto& domain = model.variables(i).domain();
+                if (domain.empty() || domain[0] < 0 || domain[domain.size() - 1] > 1) {
+                    return "variable #" + std::to_string(i) + " is not Boolean";
+                }
+            }
+
+            for (int c = 0; c < model.constraints_size(); ++c) {
+                const ConstraintProto& ct = model.constraints(c);
+                if (ct.enforcement_literal_size() > 0 && weights[c] == 0) {
+                    return "constraint #" + std::to_string(c)
+                        + " has enforcement literals that are not a soft constraint";
+                }
+                switch (ct.constraint_case()) {
+                    case ConstraintProto::kBoolOr:
+                    case ConstraintProto::kBoolAnd:
+                    case ConstraintProto::kAtMostOne:
+                    case ConstraintProto::kExactlyOne:
+                        break;
+                    case ConstraintProto::kLinear:
+                        if (ct.linear().domain_size() != 2) {
+                            return "constraint #" + std::to_string(c) + " has a domain with holes";
+                        }
+                        break;
+                    default:
+                        return "constraint #" + std::to_string(c)
+                            + " is not supported by the OPB format";
+                }
+            }
+
+            if (model.has_floating_point_objective()) {
+                return "floating point objectives are not supported by the OPB format";
+            }
+            const double scaling_factor = model.objective().scaling_factor();
+            if (scaling_factor != 0.0 && scaling_factor != 1.0 && scaling_factor != -1.0) {
+                return "scaled objectives are not supported by the OPB format";
+            }
+            return "";
+        }
+
+        std::string ToString() const {
+            std::ostringstream constraints;
+            int num_constraints = 0;
+            const auto write_terms = [&](std::ostringstream& out, const auto& vars, const auto& coeffs, int64_t sign) {
+                for (size_t i = 0; i < static_cast<size_t>(vars.size()); ++i) {
+                    const int64_t coeff = sign * coeffs[i];
+                    out << (coeff >= 0 ? "+" : "") << coeff << " " << Literal(vars[i]) << " ";
+                }
+            };
+            const auto write_literals = [&](const auto& literals, int64_t sign) {
+                for (const int ref : literals) {
+                    constraints << (sign >= 0 ? "+" : "") << sign << " " << Literal(ref) << " ";
+                }
+            };
+            const auto write_weight = [&](int c) {
+                if (weights[c] > 0) {
+                    constraints << "[" << weights[c] << "] ";
+                }
+            };
+            const auto end_constraint = [&](const char* op, int64_t rhs) {
+                constraints << op << " " << rhs << " ;\n";
+                ++num_constraints;
+            };
+
+            for (int i = 0; i < model.variables_size(); ++i) {
+                const auto& domain = model.variables(i).domain();
+                if (domain[0] == domain[domain.size() - 1]) {
+                    constraints << "+1 " << Literal(i) << " ";
+                    end_constraint("=", domain[0]);
+                }
+            }
+
+            int64_t top_cost = 0;
+            for (int c = 0; c < model.constraints_size(); ++c) {
+                const ConstraintProto& ct = model.constraints(c);
+                if (c == top_cost_constraint) {
+                    top_cost = ct.linear().domain(1) + 1;
+                    continue;
+                }
+
+                switch (ct.constraint_case()) {
+                    case ConstraintProto::kBoolOr:
+                        write_weight(c);
+                        write_literals(ct.bool_or().literals(), 1);
+                        end_constraint(">=", 1);
+                        break;
+                    case ConstraintProto::kBoolAnd:
+                        write_weight(c);
+                        write_literals(ct.bool_and().literals(), 1);
+                        end_constraint(">=", ct.bool_and().literals_size());
+                        break;
+                    case ConstraintProto::kAtMostOne:
+                        write_weight(c);
+                        write_literals(ct.at_most_one().literals(), -1);
+                        end_constraint(">=", -1);
+                        break;
+                    case ConstraintProto::kExactlyOne:
+                        write_weight(c);
+                        write_literals(ct.exactly_one().literals(), 1);
+                        end_constraint("=", 1);
+                        break;
+                    case ConstraintProto::kLinear: {
+                        // A value violates at most one side, so each side of a soft
+                        // constraint can be penalized with its full weight.
+                        const auto& linear = ct.linear();
+                        const int64_t lb = linear.domain(0);
+                        const int64_t ub = linear.domain(1);
+                        if (lb == ub) {
+                            write_weight(c);
+                            write_terms(constraints, linear.vars(), linear.coeffs(), 1);
+                            end_constraint("=", lb);
+                            break;
+                        }
+                        if (lb != std::numeric_limits<int64_t>::min()) {
+                            write_weight(c);
+                            write_terms(constraints, linear.vars(), linear.coeffs(), 1);
+                            end_constraint(">=", lb);
+                        }
+                        if (ub != std::numeric_limits<int64_t>::max()) {
+                            write_weight(c);
+                            write_terms(constraints, linear.vars(), linear.coeffs(), -1);
+                            end_constraint(">=", -ub);
+                        }
+                        break;
+                    }
+                    default:
+                        break;
+                }
+            }
+
+            std::ostringstream out;
+            out << "* #variable= " << model.variables_size()
+                << " #constraint= " << num_constraints;
+            if (IsSoft()) {
+                int num_soft = 0;
+                int64_t min_cost = std::numeric_limits<int64_t>::max();
+                int64_t max_cost = 0;
+                int64_t sum_cost = 0;
+                for (const int64_t weight : weights) {
+                    if (weight > 0) {
+                        ++num_soft;
+                        min_cost = std::min(min_cost, weight);
+                        max_cost = std::max(max_cost, weight);
+                        sum_cost += weight;
+                    }
+                }
+                out << " #soft= " << num_soft << " mincost= " << min_cost
+                    << " maxcost= " << max_cost << " sumcost= " << sum_cost << "\n";
+                out << "soft: ";
+                if (top_cost_constraint >= 0) {
+                    out << top_cost << " ";
+                }
+                out << ";\n";
+            } else {
+                out << "\n";
+            }
+
+            const int64_t offset = static_cast<int64_t>(model.objective().offset());
+            const bool has_terms = std::any_of(
+                objective_coeffs.begin(), objective_coeffs.end(),
+                [](int64_t coeff) { return coeff != 0; }
+            );
+            if (has_terms || offset != 0) {
+                out << "min: ";
+                for (int i = 0; i < model.variables_size(); ++i) {
+                    if (objective_coeffs[i] != 0) {
+                        out << (objective_coeffs[i] >= 0 ? "+" : "") << objective_coeffs[i]
+                            << " " << Literal(i) << " ";
+                    }
+                }
+                if (offset != 0) {
+                    out << (offset >= 0 ? "+" : "") << offset << " ";
+                }
+                out << ";\n";
+            }
+            out << constraints.str();
+            return out.str();
+        }
+    };
+
+    }  // namespace opb
+    }  // namespace or_tools_rs
+}}
+
+/// A pseudo-Boolean problem in the OPB format, or in the weighted Boolean
+/// optimization WBO format, loaded into a CP-SAT model.
+///
+/// The objective (`min:`) is minimized. Each soft constraint of a WBO problem
+/// is only enforced when a new "violation" literal is false, and the weight of
+/// this literal goes in the objective; the optional top cost of the `soft:`
+/// line bounds the total cost of the violated constraints. Non-linear terms
+/// are replaced by a new literal equal to the conjunction of their literals.
+///
+/// The variables declared by the `* #variable= <n>` header are all created,
+/// and the literals must refer to one of them.
+pub struct OpbProblem<'model> {
+    /// The variable `x<i>` of the file is `variables[i - 1]`
+    variables: Vec<BoolVar<'model>>,
+    /// The violation literals of the soft constraints, with their weights, in
+    /// order of appearance
+    soft_constraints: Vec<(BoolVar<'model>, i64)>,
+}
+
+impl<'model> OpbProblem<'model> {
+    /// Loads an OPB or WBO problem into the given model.
+    pub fn load(model: &'model CpModelBuilder, reader: impl BufRead) -> io::Result<Self> {
+        let mut loader = Loader {
+            model,
+            variables: Vec::default(),
+            num_variables: None,
+            soft_constraints: Vec::default(),
+            objective: None,
+            top_cost: None,
+        };
+
+        let mut statement = String::new();
+        for line in reader.lines() {
+            let line = line?;
+            let line = line.trim();
+            if line.starts_with('*') {
+                loader.parse_comment(line).map_err(invalid_data)?;
+                continue;
+            }
+
+            for (index, part) in line.split(';').enumerate() {
+                if index > 0 {
+                    loader.parse_statement(&statement).map_err(invalid_data)?;
+                    statement.clear();
+                }
+                statement.push(' ');
+                statement.push_str(part);
+            }
+        }
+        if !statement.trim().is_empty() {
+            return Err(invalid_data(format!(
+                "statement {:?} is not terminated by ';'",
+                statement.trim(),
+            )));
+        }
+
+        loader.finish();
+        Ok(Self {
+            variables: loader.variables,
+            soft_constraints: loader.soft_constraints,
+        })
+    }
+
+    /// Loads an OPB or WBO file into the given model.
+    pub fn load_file(model: &'model CpModelBuilder, path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::load(model, BufReader::new(File::open(path)?))
+    }
+
+    /// Returns the variables of the problem. The variable `x<i>` of the file is
+    /// at index `i - 1`.
+    pub fn variables(&self) -> &[BoolVar<'model>] {
+        &self.variables
+    }
+
+    /// Returns, for each soft constraint in order of appearance, the literal
+    /// that is true when it is violated and its weight.
+    pub fn soft_constraints(&self) -> &[(BoolVar<'model>, i64)] {
+        &self.soft_constraints
+    }
+}
+
+struct Loader<'model> {
+    model: &'model CpModelBuilder,
+    variables: Vec<BoolVar<'model>>,
+    /// Number of variables declared by the header, if any
+    num_variables: Option<usize>,
+    soft_constraints: Vec<(BoolVar<'model>, i64)>,
+    objective: Option<LinearExpr>,
+    top_cost: Option<i64>,
+}
+
+impl<'model> Loader<'model> {
+    /// Reads the number of variables from the `* #variable= <n>` header, and
+    /// ignores the other comments.
+    fn parse_comment(&mut self, line: &str) -> Result<(), String> {
+        let mut tokens = line.split_whitespace();
+        if self.num_variables.is_some() || !tokens.any(|token| token == "#variable=") {
+            return Ok(());
+        }
+
+        let num_variables = tokens
+            .next()
+            .and_then(|token| token.parse::<usize>().ok())
+            .filter(|&num_variables| num_variables <= MAX_NUM_VARIABLES)
+            .ok_or_else(|| format!("invalid number of variables in {line:?}"))?;
+        if self.variables.len() > num_variables {
+            return Err(format!("more than {num_variables} variables are used"));
+        }
+        while self.variables.len() < num_variables {
+            self.variables.push(self.model.new_bool_var());
+        }
+        self.num_variables = Some(num_variables);
+        Ok(())
+    }
+
+    fn parse_statement(&mut self, statement: &str) -> Result<(), String> {
+        let statement = statement.trim();
+        if statement.is_empty() {
+            return Ok(());
+        }
+
+        if let Some(objective) = statement.strip_prefix("min:") {
+            if self.objective.is_some() {
+                return Err("duplicated objective".into());
+            }
+            let tokens: Vec<_> = objective.split_whitespace().collect();
+            self.objective = Some(self.parse_terms(&tokens)?);
+            return Ok(());
+        }
+        if let Some(top_cost) = statement.strip_prefix("soft:") {
+            let top_cost = top_cost.trim();
+            if !top_cost.is_empty() {
+                self.top_cost = Some(
+                    top_cost
+                        .parse()
+                        .map_err(|_| format!("invalid top cost {top_cost:?}"))?,
+                );
+            }
+            return Ok(());
+        }
+
+        let mut tokens: Vec<_> = statement.split_whitespace().collect();
+
+        // A soft constraint starts with its weight in brackets.
+        let weight = match tokens.first() {
+            Some(token) if token.starts_with('[') => {
+                let weight = token
+                    .strip_prefix('[')
+                    .and_then(|weight| weight.strip_suffix(']'))
+                    .and_then(|weight| weight.trim().parse::<i64>().ok())
+                    .ok_or_else(|| format!("invalid weight {token:?}"))?;
+                tokens.remove(0);
+                Some(weight)
+            }
+            _ => None,
+        };
+
+        let (operator_index, operator) = tokens
+            .iter()
+            .enumerate()
+            .find(|(_, token)| matches!(**token, ">=" | "<=" | "="))
+            .map(|(index, token)| (index, *token))
+            .ok_or_else(|| format!("no relational operator in {statement:?}"))?;
+        let expr = self.parse_terms(&tokens[..operator_index])?;
+        let rhs = match &tokens[operator_index + 1..] {
+            [rhs] => rhs
+                .parse::<i64>()
+                .map_err(|_| format!("invalid right-hand side {rhs:?}"))?,
+            _ => return Err(format!("invalid right-hand side in {statement:?}")),
+        };
+
+        let constraint = match operator {
+            ">=" => self.model.add_linear_ge(expr, rhs),
+            "<=" => self.model.add_linear_le(expr, rhs),
+            _ => self.model.add_linear_eq(expr, rhs),
+        };
+        if let Some(weight) = weight {
+            let violated = self.model.new_bool_var();
+            constraint.only_enforce_if(&[!violated]);
+            self.soft_constraints.push((violated, weight));
+        }
+        Ok(())
+    }
+
+    /// Parses a sum of terms, each being an integer coefficient followed by one
+    /// or more literals, or by none for a constant.
+    fn parse_terms(&mut self, tokens: &[&str]) -> Result<LinearExpr, String> {
+        let mut expr = LinearExpr::default();
+
+        let mut tokens = tokens.iter().peekable();
+        while let Some(coefficient) = tokens.next() {
+            let coefficient: i64 = coefficient
+                .parse()
+                .map_err(|_| format!("invalid coefficient {coefficient:?}"))?;
+
+            let mut literals = Vec::new();
+            while let Some(literal) = tokens.next_if(|token| token.parse::<i64>().is_err()) {
+                literals.push(self.literal(literal)?);
+            }
+
+            let literal = match literals.as_slice() {
+                [] => {
+                    // A constant term, such as the offset of a written objective
+                    expr += coefficient;
+                    continue;
+                }
+                [literal] => *literal,
+                literals => self.product(literals),
+            };
+            expr += coefficient * literal;
+        }
+        Ok(expr)
+    }
+
+    fn literal(&mut self, token: &str) -> Result<BoolVar<'model>, String> {
+        let (is_positive, name) = match token.strip_prefix('~') {
+            Some(name) => (false, name),
+            None => (true, token),
+        };
+        let index = name
+            .strip_prefix('x')
+            .and_then(|index| index.parse::<usize>().ok())
+            .filter(|&index| index > 0)
+            .ok_or_else(|| format!("invalid literal {token:?}"))?
+            - 1;
+        if index >= self.num_variables.unwrap_or(MAX_NUM_VARIABLES) {
+            return Err(format!("literal {token:?} is out of range"));
+        }
+
+        while self.variables.len() <= index {
+            self.variables.push(self.model.new_bool_var());
+        }
+
+        let var = self.variables[index];
+        Ok(if is_positive { var } else { !var })
+    }
+
+    /// Returns a new literal equal to the conjunction of the given ones.
+    fn product(&self, literals: &[BoolVar<'model>]) -> BoolVar<'model> {
+        let product = self.model.new_bool_var();
+        self.model
+            .add_bool_and(literals)
+            .only_enforce_if(&[product]);
+
+        let mut clause: Vec<_> = literals.iter().map(|&literal| !literal).collect();
+        clause.push(product);
+        self.model.add_bool_or(&clause);
+        product
+    }
+
+    fn finish(&mut self) {
+        let mut objective = self.objective.take().unwrap_or_default();
+
+        if !self.soft_constraints.is_empty() {
+            let cost: LinearExpr = self
+                .soft_constraints
+                .iter()
+                .map(|&(violated, weight)| weight * violated)
+                .sum();
+            if let Some(top_cost) = self.top_cost {
+                self.model.add_linear_lt(&cost, top_cost);
+            }
+            objective += cost;
+        }
+
+        if !objective.is_constant() {
+            self.model.minimize(objective);
+        }
+    }
+}
+
+/// Writes a CP-SAT model in the OPB format, or in the WBO format if it has soft
+/// constraints.
+///
+/// All the variables must be Boolean, and only the `bool_or`, `bool_and`,
+/// `at_most_one`, `exactly_one` and linear constraints are supported. A linear
+/// constraint domain must be a single interval. A constraint may only have
+/// enforcement literals if it is soft, as loaded by `OpbProblem::load()`: it
+/// is enforced by the negation of a literal whose only other use is its weight
+/// in the objective, and possibly the top cost. The objective is written as a
+/// minimization, with its offset as a constant term; a maximization is written
+/// as the minimization of the opposite objective.
+///
+/// Returns an `InvalidInput` error if the model cannot be written.
+pub fn write_opb(model: &CpModelBuilder, mut writer: impl Write) -> io::Result<()> {
+    let model_proto = &model.build();
+
+    let error: String = unsafe {
+        cpp!([
+            model_proto as "const operations_research::sat::CpModelProto*"
+        ] -> CxxString as "std::unique_ptr<std::string>"
+            {
+                return std::make_unique<std::string>(or_tools_rs::opb::WboModel(*model_proto).Error());
+            }
+        )
+    }
+    .into();
+    if !error.is_empty() {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, error));
+    }
+
+    let output = unsafe {
+        cpp!([
+            model_proto as "const operations_research::sat::CpModelProto*"
+        ] -> CxxString as "std::unique_ptr<std::string>"
+            {
+                return std::make_unique<std::string>(or_tools_rs::opb::WboModel(*model_proto).ToString());
+            }
+        )
+    };
+    writer.write_all(output.as_bytes())
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
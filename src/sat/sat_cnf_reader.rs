@@ -0,0 +1,283 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, Write},
+    path::Path,
+};
+
+use super::cp_model::{BoolVar, CpModelBuilder, CpSolverResponse, CpSolverStatus, LinearExpr};
+
+/// The variables are created as declared by the `p` header or, without one, as
+/// their literals appear. This bounds their number, so that a single huge number
+/// cannot make the loader allocate that many variables.
+const MAX_NUM_VARIABLES: usize = 1 << 24;
+
+/// A problem in the DIMACS CNF format, or in the weighted MaxSAT WCNF format,
+/// loaded into a CP-SAT model.
+///
+/// Hard clauses become `bool_or` constraints. Each soft clause with more than
+/// one literal gets a new "violation" literal added to it, whose weight goes in
+/// the objective to minimize; a unit soft clause directly puts the weight on
+/// the negation of its literal.
+///
+/// Both WCNF flavors are supported: the one with a `p wcnf` header where the
+/// hard clauses have the "top" weight, and the one without header where the hard
+/// clauses start with `h`.
+pub struct CnfProblem<'model> {
+    /// The variable `i` of the file is `variables[i - 1]`
+    variables: Vec<BoolVar<'model>>,
+    /// Whether the problem has an objective
+    is_weighted: bool,
+}
+
+impl<'model> CnfProblem<'model> {
+    /// Loads a DIMACS CNF or WCNF problem into the given model.
+    pub fn load(model: &'model CpModelBuilder, reader: impl BufRead) -> io::Result<Self> {
+        let mut loader = Loader {
+            model,
+            variables: Vec::default(),
+            format: Format::Unknown,
+            objective: LinearExpr::default(),
+            total_weight: 0,
+            num_soft_clauses: 0,
+            weight: None,
+            clause: Vec::default(),
+        };
+
+        for (line_index, line) in reader.lines().enumerate() {
+            let line = line?;
+            loader
+                .parse_line(&line)
+                .map_err(|error| invalid_data(format!("line {}: {error}", line_index + 1)))?;
+            if line.trim_start().starts_with('%') {
+                // End marker of some SATLIB benchmarks.
+                break;
+            }
+        }
+        if !loader.clause.is_empty() || loader.weight.is_some() {
+            return Err(invalid_data(
+                "the last clause is not terminated by 0".into(),
+            ));
+        }
+
+        let is_weighted = loader.num_soft_clauses > 0;
+        if is_weighted {
+            model.minimize(loader.objective);
+        }
+
+        Ok(Self {
+            variables: loader.variables,
+            is_weighted,
+        })
+    }
+
+    /// Loads a DIMACS CNF or WCNF file into the given model.
+    pub fn load_file(model: &'model CpModelBuilder, path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::load(model, BufReader::new(File::open(path)?))
+    }
+
+    /// Returns the variables of the problem. The variable `i` of the file is at
+    /// index `i - 1`.
+    pub fn variables(&self) -> &[BoolVar<'model>] {
+        &self.variables
+    }
+
+    /// Returns whether the problem has soft clauses, and thus an objective.
+    pub fn is_weighted(&self) -> bool {
+        self.is_weighted
+    }
+
+    /// Writes the result of a solve in the DIMACS output format: a `s` status
+    /// line, an `o` cost line for weighted problems, and a `v` line with the
+    /// assignment of all the variables.
+    pub fn write_solution(
+        &self,
+        response: &CpSolverResponse,
+        mut writer: impl Write,
+    ) -> io::Result<()> {
+        let status = response.status();
+        let status_line = match status {
+            CpSolverStatus::Optimal if self.is_weighted => "OPTIMUM FOUND",
+            CpSolverStatus::Optimal | CpSolverStatus::Feasible => "SATISFIABLE",
+            CpSolverStatus::Infeasible => "UNSATISFIABLE",
+            CpSolverStatus::Unknown | CpSolverStatus::ModelInvalid => "UNKNOWN",
+        };
+        writeln!(writer, "s {status_line}")?;
+
+        if !matches!(status, CpSolverStatus::Optimal | CpSolverStatus::Feasible) {
+            return Ok(());
+        }
+        if self.is_weighted {
+            writeln!(writer, "o {}", response.objective_value().round() as i64)?;
+        }
+
+        write!(writer, "v")?;
+        for (index, var) in self.variables.iter().enumerate() {
+            let literal = index as i64 + 1;
+            if response.boolean_value(var) {
+                write!(writer, " {literal}")?;
+            } else {
+                write!(writer, " -{literal}")?;
+            }
+        }
+        if !self.is_weighted {
+            write!(writer, " 0")?;
+        }
+        writeln!(writer)
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Format {
+    /// No header yet: clauses are read as in the WCNF format without header
+    Unknown,
+    /// `p cnf <num_variables> <num_clauses>`
+    Cnf,
+    /// `p wcnf <num_variables> <num_clauses> [<top>]`
+    Wcnf { top: Option<i64> },
+}
+
+struct Loader<'model> {
+    model: &'model CpModelBuilder,
+    variables: Vec<BoolVar<'model>>,
+    format: Format,
+    objective: LinearExpr,
+    /// Sum of the weights of the soft clauses, which must fit in an `i64`
+    total_weight: i64,
+    num_soft_clauses: usize,
+    /// Weight of the clause being read, `None` for a hard clause
+    weight: Option<Option<i64>>,
+    clause: Vec<BoolVar<'model>>,
+}
+
+impl<'model> Loader<'model> {
+    fn parse_line(&mut self, line: &str) -> Result<(), String> {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('c') || line.starts_with('%') {
+            return Ok(());
+        }
+        if line.starts_with('p') {
+            return self.parse_header(line);
+        }
+
+        for token in line.split_whitespace() {
+            // The weight comes first in a weighted clause.
+            if self.weight.is_none() && self.clause.is_empty() && self.format != Format::Cnf {
+                self.weight = Some(self.parse_weight(token)?);
+                continue;
+            }
+
+            let value: i64 = token
+                .parse()
+                .map_err(|_| format!("invalid literal {token:?}"))?;
+            if value == 0 {
+                self.add_clause()?;
+            } else {
+                let literal = self.literal(value)?;
+                self.clause.push(literal);
+            }
+        }
+        Ok(())
+    }
+
+    fn parse_header(&mut self, line: &str) -> Result<(), String> {
+        if self.format != Format::Unknown {
+            return Err("duplicated problem line".into());
+        }
+
+        let tokens: Vec<_> = line.split_whitespace().collect();
+        let parse = |token: &str| {
+            token
+                .parse::<i64>()
+                .map_err(|_| format!("invalid number {token:?} in problem line"))
+        };
+        let num_variables = match tokens.as_slice() {
+            ["p", "cnf", num_variables, _] => {
+                self.format = Format::Cnf;
+                parse(num_variables)?
+            }
+            ["p", "wcnf", num_variables, _] => {
+                self.format = Format::Wcnf { top: None };
+                parse(num_variables)?
+            }
+            ["p", "wcnf", num_variables, _, top] => {
+                self.format = Format::Wcnf {
+                    top: Some(parse(top)?),
+                };
+                parse(num_variables)?
+            }
+            _ => return Err(format!("invalid problem line {line:?}")),
+        };
+        if !(0..=MAX_NUM_VARIABLES as i64).contains(&num_variables) {
+            return Err(format!("invalid number of variables {num_variables}"));
+        }
+
+        while (self.variables.len() as i64) < num_variables {
+            self.variables.push(self.model.new_bool_var());
+        }
+        Ok(())
+    }
+
+    /// Returns `None` for a hard clause, or the weight of a soft clause.
+    fn parse_weight(&self, token: &str) -> Result<Option<i64>, String> {
+        if token == "h" && self.format == Format::Unknown {
+            return Ok(None);
+        }
+
+        let weight: i64 = token
+            .parse()
+            .map_err(|_| format!("invalid weight {token:?}"))?;
+        if weight < 0 {
+            return Err(format!("negative weight {weight}"));
+        }
+        match self.format {
+            Format::Wcnf { top: Some(top) } if weight >= top => Ok(None),
+            _ => Ok(Some(weight)),
+        }
+    }
+
+    fn literal(&mut self, value: i64) -> Result<BoolVar<'model>, String> {
+        let index = (value.unsigned_abs() - 1) as usize;
+        if self.format == Format::Unknown && index < MAX_NUM_VARIABLES {
+            while self.variables.len() <= index {
+                self.variables.push(self.model.new_bool_var());
+            }
+        } else if index >= self.variables.len() {
+            return Err(format!("literal {value} is out of range"));
+        }
+
+        let var = self.variables[index];
+        Ok(if value > 0 { var } else { !var })
+    }
+
+    fn add_clause(&mut self) -> Result<(), String> {
+        let clause = std::mem::take(&mut self.clause);
+        match self.weight.take().flatten() {
+            None => {
+                self.model.add_bool_or(&clause);
+            }
+            Some(weight) => {
+                self.total_weight = self
+                    .total_weight
+                    .checked_add(weight)
+                    .ok_or("the sum of the soft clause weights overflows")?;
+                self.num_soft_clauses += 1;
+                match clause.as_slice() {
+                    [] => self.objective += weight,
+                    [literal] => self.objective += weight * !*literal,
+                    literals => {
+                        let violated = self.model.new_bool_var();
+                        let mut literals = literals.to_vec();
+                        literals.push(violated);
+                        self.model.add_bool_or(&literals);
+                        self.objective += weight * violated;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
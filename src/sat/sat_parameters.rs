@@ -0,0 +1,307 @@
+use std::{ffi::c_int, io};
+
+#[cfg(feature = "proto")]
+use or_tools_proto::{prost::Message, sat as proto};
+
+use crate::utils::string::CxxString;
+
+// IMPORT CXX LIBRARY
+cpp! {{
+    #include <memory>
+    #include <string>
+
+    #include <google/protobuf/io/tokenizer.h>
+    #include <google/protobuf/text_format.h>
+
+    #include "ortools/sat/sat_parameters.pb.h"
+}}
+
+cpp_class!(
+    /// Contains the definitions for all the sat algorithm parameters and their
+    /// default values.
+    pub unsafe struct SatParameters as "operations_research::sat::SatParameters"
+);
+
+impl SatParameters {
+    /// Create a default parameters
+    pub fn new() -> Self {
+        unsafe {
+            cpp!([
+            ] -> SatParameters as "operations_research::sat::SatParameters"
+                {
+                    return operations_research::sat::SatParameters();
+                }
+            )
+        }
+    }
+
+    /// Creates parameters from a serialized SatParameters, as produced by
+    /// `export_to_bytes()`.
+    ///
+    /// Returns `None` if the bytes could not be parsed.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut parameters = Self::new();
+        let parameters_ref = &mut parameters;
+
+        let bytes_ptr = bytes.as_ptr();
+        let bytes_len = bytes.len();
+
+        let parsed = unsafe {
+            cpp!([
+                parameters_ref as "operations_research::sat::SatParameters*",
+                bytes_ptr as "const void*",
+                bytes_len as "size_t"
+            ] -> bool as "bool"
+                {
+                    return parameters_ref->ParseFromArray(bytes_ptr, static_cast<int>(bytes_len));
+                }
+            )
+        };
+        parsed.then_some(parameters)
+    }
+
+    /// Creates parameters from the pure-Rust SatParameters.
+    ///
+    /// Returns `None` if the native library rejects the message.
+    #[cfg(feature = "proto")]
+    pub fn from_proto(proto: &proto::SatParameters) -> Option<Self> {
+        Self::from_bytes(&proto.encode_to_vec())
+    }
+
+    /// Returns the serialized SatParameters.
+    pub fn export_to_bytes(&self) -> Vec<u8> {
+        unsafe {
+            cpp!([
+                self as "const operations_research::sat::SatParameters*"
+            ] -> CxxString as "std::unique_ptr<std::string>"
+                {
+                    return std::make_unique<std::string>(self->SerializeAsString());
+                }
+            )
+        }
+        .as_bytes()
+        .to_vec()
+    }
+
+    /// Returns the parameters as a pure-Rust message.
+    #[cfg(feature = "proto")]
+    pub fn export_to_proto(&self) -> proto::SatParameters {
+        proto::SatParameters::decode(self.export_to_bytes().as_slice())
+            .expect("the native library should serialize valid SatParameters")
+    }
+
+    /// Merges the parameters given in the protobuf text format into these ones,
+    /// so that any parameter without a dedicated setter can be configured, e.g.
+    /// `"num_workers: 8, linearization_level: 2"`.
+    ///
+    /// Returns an `InvalidData` error with the parser message if the text could
+    /// not be parsed.
+    pub fn merge_from_text(&mut self, text: &str) -> io::Result<()> {
+        let text_ptr = text.as_ptr();
+        let text_len = text.len();
+
+        let error: String = unsafe {
+            cpp!([
+                self as "operations_research::sat::SatParameters*",
+                text_ptr as "const char*",
+                text_len as "size_t"
+            ] -> CxxString as "std::unique_ptr<std::string>"
+                {
+                    class Collector : public google::protobuf::io::ErrorCollector {
+                    public:
+                        std::string errors;
+
+                        void RecordError(
+                            int line,
+                            google::protobuf::io::ColumnNumber column,
+                            absl::string_view message
+                        ) override {
+                            if (!errors.empty()) {
+                                errors += "; ";
+                            }
+                            errors += std::to_string(line + 1) + ":" + std::to_string(column + 1)
+                                + ": " + std::string(message);
+                        }
+                    };
+
+                    Collector collector;
+                    google::protobuf::TextFormat::Parser parser;
+                    parser.RecordErrorsTo(&collector);
+                    if (!parser.MergeFromString(std::string(text_ptr, text_len), self)
+                        && collector.errors.empty()) {
+                        collector.errors = "failed to parse SatParameters";
+                    }
+                    return std::make_unique<std::string>(std::move(collector.errors));
+                }
+            )
+        }
+        .into();
+
+        if error.is_empty() {
+            Ok(())
+        } else {
+            Err(io::Error::new(io::ErrorKind::InvalidData, error))
+        }
+    }
+
+    /// Set the number of parallel workers to use during search.
+    ///
+    /// A value of 0 means the solver will try to use all cores on the machine.
+    /// A number of 1 means no parallelism.
+    pub fn set_num_workers(&mut self, value: c_int) {
+        unsafe {
+            cpp!([
+                self as "operations_research::sat::SatParameters*",
+                value as "int32_t"
+            ]
+                {
+                    return self->set_num_workers(value);
+                }
+            )
+        }
+    }
+
+    /// Set the maximum time allowed in seconds to solve a problem.
+    /// The counter will starts at the beginning of the Solve() call.
+    pub fn set_max_time_in_seconds(&mut self, value: f64) {
+        unsafe {
+            cpp!([
+                self as "operations_research::sat::SatParameters*",
+                value as "double"
+            ]
+                {
+                    return self->set_max_time_in_seconds(value);
+                }
+            )
+        }
+    }
+
+    /// Set the seed for the random number generator.
+    ///
+    /// At the beginning of each solve, the random number generator used in some
+    /// part of the solver is reinitialized to this seed. If you change the random
+    /// seed, the solver may make different choices during the solving process.
+    pub fn set_random_seed(&mut self, value: c_int) {
+        unsafe {
+            cpp!([
+                self as "operations_research::sat::SatParameters*",
+                value as "int32_t"
+            ]
+                {
+                    return self->set_random_seed(value);
+                }
+            )
+        }
+    }
+
+    /// Whether the solver should log the search progress.
+    pub fn set_log_search_progress(&mut self, value: bool) {
+        unsafe {
+            cpp!([
+                self as "operations_research::sat::SatParameters*",
+                value as "bool"
+            ]
+                {
+                    return self->set_log_search_progress(value);
+                }
+            )
+        }
+    }
+
+    /// Whether we enumerate all solutions of a problem without objective.
+    ///
+    /// Note that setting this to true automatically disable some presolve
+    /// reduction that can remove feasible solution. That is it has the same
+    /// effect as setting keep_all_feasible_solutions_in_presolve.
+    pub fn set_enumerate_all_solutions(&mut self, value: bool) {
+        unsafe {
+            cpp!([
+                self as "operations_research::sat::SatParameters*",
+                value as "bool"
+            ]
+                {
+                    return self->set_enumerate_all_solutions(value);
+                }
+            )
+        }
+    }
+
+    /// Stop the search when the gap between the best feasible objective (O) and
+    /// our best objective bound (B) is smaller than a relative limit.
+    /// The relative gap is computed as |O - B| / max(1, |O|).
+    pub fn set_relative_gap_limit(&mut self, value: f64) {
+        unsafe {
+            cpp!([
+                self as "operations_research::sat::SatParameters*",
+                value as "double"
+            ]
+                {
+                    return self->set_relative_gap_limit(value);
+                }
+            )
+        }
+    }
+
+    /// Stop the search when the gap between the best feasible objective (O) and
+    /// our best objective bound (B) is smaller than an absolute limit.
+    /// The absolute gap is computed as |O - B|.
+    pub fn set_absolute_gap_limit(&mut self, value: f64) {
+        unsafe {
+            cpp!([
+                self as "operations_research::sat::SatParameters*",
+                value as "double"
+            ]
+                {
+                    return self->set_absolute_gap_limit(value);
+                }
+            )
+        }
+    }
+
+    /// Whether we presolve the cp_model before solving it.
+    pub fn set_cp_model_presolve(&mut self, value: bool) {
+        unsafe {
+            cpp!([
+                self as "operations_research::sat::SatParameters*",
+                value as "bool"
+            ]
+                {
+                    return self->set_cp_model_presolve(value);
+                }
+            )
+        }
+    }
+
+    /// Experimental. If this is true, then we interleave all our major search
+    /// strategy and distribute the work amongst num_workers.
+    ///
+    /// The search is deterministic (independently of num_workers!), and we
+    /// schedule and wait for interleave_batch_size task to be completed before
+    /// synchronizing and scheduling the next batch of tasks.
+    pub fn set_interleave_search(&mut self, value: bool) {
+        unsafe {
+            cpp!([
+                self as "operations_research::sat::SatParameters*",
+                value as "bool"
+            ]
+                {
+                    return self->set_interleave_search(value);
+                }
+            )
+        }
+    }
+
+    /// For an optimization problem, stop the solver as soon as we have a solution.
+    pub fn set_stop_after_first_solution(&mut self, value: bool) {
+        unsafe {
+            cpp!([
+                self as "operations_research::sat::SatParameters*",
+                value as "bool"
+            ]
+                {
+                    return self->set_stop_after_first_solution(value);
+                }
+            )
+        }
+    }
+}
@@ -0,0 +1,402 @@
+use std::{ffi::c_int, ops::Not};
+
+use super::sat_parameters::SatParameters;
+
+// IMPORT CXX LIBRARY
+cpp! {{
+    #include <algorithm>
+    #include <vector>
+
+    #include "ortools/sat/sat_base.h"
+    #include "ortools/sat/sat_parameters.pb.h"
+    #include "ortools/sat/sat_solver.h"
+}}
+
+/// Index of a Boolean variable of a `SatSolver`.
+///
+/// Variables are numbered from zero, in their order of creation. They can only
+/// be created by `SatSolver::new_boolean_variable()`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BooleanVariable(c_int);
+
+impl BooleanVariable {
+    /// Returns the index of the variable.
+    pub fn index(self) -> c_int {
+        self.0
+    }
+
+    /// Returns the positive literal of the variable.
+    pub fn positive(self) -> Literal {
+        Literal::new(self, true)
+    }
+
+    /// Returns the negative literal of the variable.
+    pub fn negative(self) -> Literal {
+        Literal::new(self, false)
+    }
+}
+
+/// A literal is used to represent a variable or its negation. If it represents
+/// the variable it is said to be positive. If it represent its negation, it is
+/// said to be negative.
+///
+/// A literal is stored with its signed value as in the DIMACS format: the
+/// variable `v` is represented by `v + 1`, and its negation by `-(v + 1)`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Literal(c_int);
+
+impl Literal {
+    /// Creates a literal of the given variable.
+    pub fn new(variable: BooleanVariable, is_positive: bool) -> Self {
+        let signed_value = variable.0 + 1;
+        Self(if is_positive {
+            signed_value
+        } else {
+            -signed_value
+        })
+    }
+
+    /// Creates a literal from its signed value in the DIMACS format.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the signed value is zero or `c_int::MIN`, which has no
+    /// negation.
+    pub fn from_signed_value(signed_value: c_int) -> Self {
+        assert!(
+            signed_value != 0 && signed_value != c_int::MIN,
+            "a literal signed value must be non-zero and negatable",
+        );
+        Self(signed_value)
+    }
+
+    /// Returns the signed value of the literal in the DIMACS format.
+    pub fn signed_value(self) -> c_int {
+        self.0
+    }
+
+    /// Returns the variable of the literal.
+    pub fn variable(self) -> BooleanVariable {
+        BooleanVariable(self.0.abs() - 1)
+    }
+
+    /// Returns whether the literal represents the variable itself.
+    pub fn is_positive(self) -> bool {
+        self.0 > 0
+    }
+
+    /// Returns the negation of the literal.
+    pub fn negated(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl Not for Literal {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        self.negated()
+    }
+}
+
+cpp_class!(
+    #[doc(hidden)]
+    unsafe struct SatSolverInner as "operations_research::sat::SatSolver"
+);
+
+/// The main SAT solver.
+///
+/// It currently implements the CDCL algorithm. The solver is incremental:
+/// clauses and variables can be added between two calls to
+/// `solve_with_assumptions()`.
+pub struct SatSolver {
+    /// Original solver
+    inner: Box<SatSolverInner>,
+    /// Values of the variables in the last model found
+    last_model: Vec<bool>,
+    /// Subset of the assumptions of the last solve that are incompatible
+    last_core: Vec<Literal>,
+}
+
+impl Default for SatSolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SatSolver {
+    /// Creates an empty solver.
+    pub fn new() -> Self {
+        Self {
+            inner: unsafe {
+                cpp!([
+                ] -> Box<SatSolverInner> as "operations_research::sat::SatSolver*"
+                    {
+                        return new operations_research::sat::SatSolver();
+                    }
+                )
+            },
+            last_model: Vec::default(),
+            last_core: Vec::default(),
+        }
+    }
+
+    /// Parameters management. Note that calling SetParameters() will reset the
+    /// value of many heuristics. For instance:
+    /// - The restart strategy will be reinitialized.
+    /// - The random seed and random generator will be reset to the value given in
+    ///   parameters.
+    /// - The global TimeLimit singleton will be reset and time will be
+    ///   counted from this call.
+    pub fn set_parameters(&mut self, parameters: &SatParameters) {
+        let inner = self.inner.as_mut();
+
+        unsafe {
+            cpp!([
+                inner as "operations_research::sat::SatSolver*",
+                parameters as "const operations_research::sat::SatParameters*"
+            ]
+                {
+                    inner->SetParameters(*parameters);
+                }
+            )
+        }
+    }
+
+    /// Returns the number of variables of the problem.
+    pub fn num_variables(&self) -> c_int {
+        let inner = self.inner.as_ref();
+
+        unsafe {
+            cpp!([
+                inner as "const operations_research::sat::SatSolver*"
+            ] -> c_int as "int"
+                {
+                    return inner->NumVariables();
+                }
+            )
+        }
+    }
+
+    /// Creates a new variable and returns its index.
+    ///
+    /// By default, the variable will be set to false.
+    pub fn new_boolean_variable(&mut self) -> BooleanVariable {
+        let inner = self.inner.as_mut();
+
+        BooleanVariable(unsafe {
+            cpp!([
+                inner as "operations_research::sat::SatSolver*"
+            ] -> c_int as "int"
+                {
+                    return inner->NewBooleanVariable().value();
+                }
+            )
+        })
+    }
+
+    /// Adds a clause to the problem. Returns false if the problem is detected to
+    /// be UNSAT.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a literal refers to a variable that was not created by this
+    /// solver.
+    pub fn add_clause(&mut self, literals: &[Literal]) -> bool {
+        self.check_literals(literals, "clause");
+
+        let inner = self.inner.as_mut();
+
+        let literals_ptr = literals.as_ptr();
+        let literals_len = literals.len();
+
+        unsafe {
+            cpp!([
+                inner as "operations_research::sat::SatSolver*",
+                literals_ptr as "const int*",
+                literals_len as "size_t"
+            ] -> bool as "bool"
+                {
+                    std::vector<operations_research::sat::Literal> clause;
+                    clause.reserve(literals_len);
+                    for (size_t i = 0; i < literals_len; ++i) {
+                        clause.push_back(operations_research::sat::Literal(literals_ptr[i]));
+                    }
+
+                    // Clauses can only be added at the root level.
+                    inner->Backtrack(0);
+                    return inner->AddProblemClause(clause);
+                }
+            )
+        }
+    }
+
+    /// Solves the problem.
+    pub fn solve(&mut self) -> SatSolverStatus {
+        self.solve_with_assumptions(&[])
+    }
+
+    /// Solves the problem under the given assumptions.
+    ///
+    /// If the status is `Feasible`, the model can be read with `last_model()`.
+    /// If it is `AssumptionsUnsat`, `last_core()` returns a subset of the
+    /// assumptions that cannot be all true together.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an assumption refers to a variable that was not created by
+    /// this solver.
+    pub fn solve_with_assumptions(&mut self, assumptions: &[Literal]) -> SatSolverStatus {
+        self.check_literals(assumptions, "assumptions");
+
+        let inner = self.inner.as_mut();
+
+        let assumptions_ptr = assumptions.as_ptr();
+        let assumptions_len = assumptions.len();
+
+        let status = unsafe {
+            cpp!([
+                inner as "operations_research::sat::SatSolver*",
+                assumptions_ptr as "const int*",
+                assumptions_len as "size_t"
+            ] -> u8 as "uint8_t"
+                {
+                    std::vector<operations_research::sat::Literal> literals;
+                    literals.reserve(assumptions_len);
+                    for (size_t i = 0; i < assumptions_len; ++i) {
+                        literals.push_back(operations_research::sat::Literal(assumptions_ptr[i]));
+                    }
+
+                    switch (inner->ResetAndSolveWithGivenAssumptions(literals)) {
+                        case operations_research::sat::SatSolver::ASSUMPTIONS_UNSAT:
+                            return 0;
+                        case operations_research::sat::SatSolver::INFEASIBLE:
+                            return 1;
+                        case operations_research::sat::SatSolver::FEASIBLE:
+                            return 2;
+                        case operations_research::sat::SatSolver::LIMIT_REACHED:
+                            return 3;
+                        default:
+                            return 3;
+                    }
+                }
+            )
+        };
+
+        let status = match status {
+            0 => SatSolverStatus::AssumptionsUnsat,
+            1 => SatSolverStatus::Infeasible,
+            2 => SatSolverStatus::Feasible,
+            3 => SatSolverStatus::LimitReached,
+            4.. => unreachable!(),
+        };
+
+        self.last_model.clear();
+        self.last_core.clear();
+        match status {
+            SatSolverStatus::Feasible => self.read_last_model(),
+            SatSolverStatus::AssumptionsUnsat => self.read_last_core(assumptions.len()),
+            SatSolverStatus::Infeasible | SatSolverStatus::LimitReached => {}
+        }
+        status
+    }
+
+    /// Returns the values of all the variables in the model found by the last
+    /// solve, or an empty slice if it did not find one.
+    pub fn last_model(&self) -> &[bool] {
+        &self.last_model
+    }
+
+    /// Returns the value of a literal in the model found by the last solve, or
+    /// `None` if there is no such model or the variable is newer.
+    pub fn value(&self, literal: Literal) -> Option<bool> {
+        self.last_model
+            .get(literal.variable().0 as usize)
+            .map(|&value| value == literal.is_positive())
+    }
+
+    /// Returns a subset of the assumptions of the last solve that are
+    /// sufficient to make the problem UNSAT, if the last solve returned
+    /// `AssumptionsUnsat`.
+    pub fn last_core(&self) -> &[Literal] {
+        &self.last_core
+    }
+
+    fn check_literals(&self, literals: &[Literal], context: &str) {
+        let num_variables = self.num_variables();
+        assert!(
+            literals
+                .iter()
+                .all(|literal| (0..num_variables).contains(&literal.variable().0)),
+            "unknown variable in {context}",
+        );
+    }
+
+    fn read_last_model(&mut self) {
+        let inner = self.inner.as_ref();
+
+        self.last_model = vec![false; self.num_variables() as usize];
+        let model_ptr = self.last_model.as_mut_ptr();
+        let model_len = self.last_model.len();
+
+        unsafe {
+            cpp!([
+                inner as "const operations_research::sat::SatSolver*",
+                model_ptr as "bool*",
+                model_len as "size_t"
+            ]
+                {
+                    const auto& assignment = inner->Assignment();
+                    for (size_t i = 0; i < model_len; ++i) {
+                        model_ptr[i] = assignment.LiteralIsTrue(operations_research::sat::Literal(
+                            operations_research::sat::BooleanVariable(static_cast<int>(i)),
+                            true
+                        ));
+                    }
+                }
+            )
+        }
+    }
+
+    fn read_last_core(&mut self, max_len: usize) {
+        let inner = self.inner.as_mut();
+
+        let mut core = vec![0; max_len];
+        let core_ptr = core.as_mut_ptr();
+
+        let core_len = unsafe {
+            cpp!([
+                inner as "operations_research::sat::SatSolver*",
+                core_ptr as "int*",
+                max_len as "size_t"
+            ] -> usize as "size_t"
+                {
+                    const std::vector<operations_research::sat::Literal> core =
+                        inner->GetLastIncompatibleDecisions();
+                    const size_t len = std::min(core.size(), max_len);
+                    for (size_t i = 0; i < len; ++i) {
+                        core_ptr[i] = core[i].SignedValue();
+                    }
+                    return len;
+                }
+            )
+        };
+
+        core.truncate(core_len);
+        self.last_core = core.into_iter().map(Literal).collect();
+    }
+}
+
+/// The status of a `SatSolver` solve.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SatSolverStatus {
+    /// The problem is UNSAT under the given assumptions.
+    AssumptionsUnsat,
+    /// The problem is UNSAT, whatever the assumptions.
+    Infeasible,
+    /// A model satisfying all the clauses and assumptions was found.
+    Feasible,
+    /// A search limit was reached before deciding the problem.
+    LimitReached,
+}
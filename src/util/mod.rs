@@ -0,0 +1,6 @@
+//! Wrappers of the C++ utilities under `ortools/util`.
+//!
+//! Like `graph` and `sat`, this module mirrors the layout of the upstream
+//! library; the Rust-side helpers of this crate live in `utils` instead.
+
+pub mod sorted_interval_list;
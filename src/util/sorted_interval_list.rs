@@ -0,0 +1,449 @@
+use std::{
+    ffi::c_int,
+    fmt,
+    iter::{FromIterator, FusedIterator},
+    ops::RangeInclusive,
+};
+
+use crate::utils::string::CxxString;
+
+// IMPORT CXX LIBRARY
+cpp! {{
+    #include <vector>
+
+    #include "ortools/util/sorted_interval_list.h"
+}}
+
+cpp_class!(
+    /// We call "domain" any subset of Int64 = [kint64min, kint64max].
+    ///
+    /// This class can be used to represent such set efficiently as a sorted and
+    /// non-adjacent list of intervals. This is efficient as long as the size of such
+    /// list stays reasonable.
+    ///
+    /// In the comments below, the domain of `self` will always be written 'D'.
+    /// Note that all the functions are safe with respect to integer overflow.
+    #[derive(PartialEq)]
+    pub unsafe struct Domain as "operations_research::Domain"
+);
+
+impl Eq for Domain {}
+
+impl Domain {
+    /// Constructor for the common case of a single interval [left, right].
+    /// If left > right, this will result in the empty domain.
+    pub fn new(left: i64, right: i64) -> Self {
+        unsafe {
+            cpp!([
+                left as "int64_t",
+                right as "int64_t"
+            ] -> Domain as "operations_research::Domain"
+                {
+                    return operations_research::Domain(left, right);
+                }
+            )
+        }
+    }
+
+    /// Constructor for the common case of a singleton domain.
+    pub fn from_value(value: i64) -> Self {
+        unsafe {
+            cpp!([
+                value as "int64_t"
+            ] -> Domain as "operations_research::Domain"
+                {
+                    return operations_research::Domain(value);
+                }
+            )
+        }
+    }
+
+    /// Returns the full domain Int64.
+    pub fn all_values() -> Self {
+        unsafe {
+            cpp!([
+            ] -> Domain as "operations_research::Domain"
+                {
+                    return operations_research::Domain::AllValues();
+                }
+            )
+        }
+    }
+
+    /// Creates a domain from the union of an unsorted list of integer values.
+    /// Input values may be repeated, with no consequence on the output
+    pub fn from_values(values: &[i64]) -> Self {
+        let values_ptr = values.as_ptr();
+        let values_len = values.len();
+
+        unsafe {
+            cpp!([
+                values_ptr as "const int64_t*",
+                values_len as "size_t"
+            ] -> Domain as "operations_research::Domain"
+                {
+                    return operations_research::Domain::FromValues(
+                        std::vector<int64_t>(values_ptr, values_ptr + values_len)
+                    );
+                }
+            )
+        }
+    }
+
+    /// Creates a domain from the union of an unsorted list of intervals.
+    pub fn from_intervals(intervals: &[RangeInclusive<i64>]) -> Self {
+        let flat_intervals: Vec<i64> = intervals
+            .iter()
+            .flat_map(|interval| [*interval.start(), *interval.end()])
+            .collect();
+
+        Self::from_flat_intervals(&flat_intervals)
+    }
+
+    /// Same as `from_intervals()` for a flattened representation (start, end,
+    /// start, end, ...).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of values is odd.
+    pub fn from_flat_intervals(flat_intervals: &[i64]) -> Self {
+        assert!(
+            flat_intervals.len() % 2 == 0,
+            "flattened intervals should come in (start, end) pairs",
+        );
+
+        let flat_intervals_ptr = flat_intervals.as_ptr();
+        let flat_intervals_len = flat_intervals.len();
+
+        unsafe {
+            cpp!([
+                flat_intervals_ptr as "const int64_t*",
+                flat_intervals_len as "size_t"
+            ] -> Domain as "operations_research::Domain"
+                {
+                    return operations_research::Domain::FromFlatIntervals(
+                        std::vector<int64_t>(flat_intervals_ptr, flat_intervals_ptr + flat_intervals_len)
+                    );
+                }
+            )
+        }
+    }
+
+    /// Returns true if this is the empty set.
+    pub fn is_empty(&self) -> bool {
+        unsafe {
+            cpp!([
+                self as "const operations_research::Domain*"
+            ] -> bool as "bool"
+                {
+                    return self->IsEmpty();
+                }
+            )
+        }
+    }
+
+    /// Returns true iff the domain is reduced to a single value.
+    /// The domain must not be empty.
+    pub fn is_fixed(&self) -> bool {
+        unsafe {
+            cpp!([
+                self as "const operations_research::Domain*"
+            ] -> bool as "bool"
+                {
+                    return self->IsFixed();
+                }
+            )
+        }
+    }
+
+    /// Returns the number of elements in the domain. It is capped at kint64max
+    pub fn size(&self) -> i64 {
+        unsafe {
+            cpp!([
+                self as "const operations_research::Domain*"
+            ] -> i64 as "int64_t"
+                {
+                    return self->Size();
+                }
+            )
+        }
+    }
+
+    /// Returns the min value of the domain, or `None` if it is empty.
+    pub fn min(&self) -> Option<i64> {
+        if self.is_empty() {
+            return None;
+        }
+
+        Some(unsafe {
+            cpp!([
+                self as "const operations_research::Domain*"
+            ] -> i64 as "int64_t"
+                {
+                    return self->Min();
+                }
+            )
+        })
+    }
+
+    /// Returns the max value of the domain, or `None` if it is empty.
+    pub fn max(&self) -> Option<i64> {
+        if self.is_empty() {
+            return None;
+        }
+
+        Some(unsafe {
+            cpp!([
+                self as "const operations_research::Domain*"
+            ] -> i64 as "int64_t"
+                {
+                    return self->Max();
+                }
+            )
+        })
+    }
+
+    /// Returns true iff value is in Domain.
+    pub fn contains(&self, value: i64) -> bool {
+        unsafe {
+            cpp!([
+                self as "const operations_research::Domain*",
+                value as "int64_t"
+            ] -> bool as "bool"
+                {
+                    return self->Contains(value);
+                }
+            )
+        }
+    }
+
+    /// Returns true iff D is included in the given domain.
+    pub fn is_included_in(&self, domain: &Domain) -> bool {
+        unsafe {
+            cpp!([
+                self as "const operations_research::Domain*",
+                domain as "const operations_research::Domain*"
+            ] -> bool as "bool"
+                {
+                    return self->IsIncludedIn(*domain);
+                }
+            )
+        }
+    }
+
+    /// Returns the set Int64 ∖ D.
+    pub fn complement(&self) -> Domain {
+        unsafe {
+            cpp!([
+                self as "const operations_research::Domain*"
+            ] -> Domain as "operations_research::Domain"
+                {
+                    return self->Complement();
+                }
+            )
+        }
+    }
+
+    /// Returns {x ∈ Int64, ∃ e ∈ D, x = -e}.
+    ///
+    /// Note in particular that if the negation of Int64 is not Int64 but
+    /// Int64 \ {kint64min} !!
+    pub fn negation(&self) -> Domain {
+        unsafe {
+            cpp!([
+                self as "const operations_research::Domain*"
+            ] -> Domain as "operations_research::Domain"
+                {
+                    return self->Negation();
+                }
+            )
+        }
+    }
+
+    /// Returns the intersection of D and `domain`.
+    pub fn intersection(&self, domain: &Domain) -> Domain {
+        unsafe {
+            cpp!([
+                self as "const operations_research::Domain*",
+                domain as "const operations_research::Domain*"
+            ] -> Domain as "operations_research::Domain"
+                {
+                    return self->IntersectionWith(*domain);
+                }
+            )
+        }
+    }
+
+    /// Returns the union of D and `domain`.
+    pub fn union(&self, domain: &Domain) -> Domain {
+        unsafe {
+            cpp!([
+                self as "const operations_research::Domain*",
+                domain as "const operations_research::Domain*"
+            ] -> Domain as "operations_research::Domain"
+                {
+                    return self->UnionWith(*domain);
+                }
+            )
+        }
+    }
+
+    /// Returns {x ∈ Int64, ∃ a ∈ D, ∃ b ∈ domain, x = a + b}.
+    pub fn addition(&self, domain: &Domain) -> Domain {
+        unsafe {
+            cpp!([
+                self as "const operations_research::Domain*",
+                domain as "const operations_research::Domain*"
+            ] -> Domain as "operations_research::Domain"
+                {
+                    return self->AdditionWith(*domain);
+                }
+            )
+        }
+    }
+
+    /// Returns the number of disjoint intervals of the domain.
+    pub fn num_intervals(&self) -> c_int {
+        unsafe {
+            cpp!([
+                self as "const operations_research::Domain*"
+            ] -> c_int as "int"
+                {
+                    return self->NumIntervals();
+                }
+            )
+        }
+    }
+
+    /// Returns the sorted and non-adjacent intervals of the domain.
+    pub fn intervals(&self) -> Intervals<'_> {
+        Intervals {
+            domain: self,
+            front: 0,
+            back: self.num_intervals(),
+        }
+    }
+
+    /// This method returns the flattened list of interval bounds of the domain.
+    ///
+    /// Thus the domain {0, 1, 2, 5, 8, 9, 10} will return [0, 2, 5, 5,
+    /// 8, 10] (as a C++ std::vector<int64_t>, as a java or C# long[], as
+    /// a python list of integers).
+    pub fn flattened_intervals(&self) -> Vec<i64> {
+        self.intervals()
+            .flat_map(|interval| [*interval.start(), *interval.end()])
+            .collect()
+    }
+
+    fn interval(&self, index: c_int) -> RangeInclusive<i64> {
+        let mut start = 0;
+        let mut end = 0;
+
+        unsafe {
+            cpp!([
+                self as "const operations_research::Domain*",
+                index as "int",
+                mut start as "int64_t",
+                mut end as "int64_t"
+            ]
+                {
+                    const operations_research::ClosedInterval interval = (*self)[index];
+                    start = interval.start;
+                    end = interval.end;
+                }
+            )
+        }
+        start..=end
+    }
+}
+
+impl fmt::Debug for Domain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let repr = unsafe {
+            cpp!([
+                self as "const operations_research::Domain*"
+            ] -> CxxString as "std::unique_ptr<std::string>"
+                {
+                    return std::make_unique<std::string>(self->ToString());
+                }
+            )
+        };
+
+        write!(f, "{repr}")
+    }
+}
+
+impl From<i64> for Domain {
+    fn from(value: i64) -> Self {
+        Self::from_value(value)
+    }
+}
+
+impl From<RangeInclusive<i64>> for Domain {
+    fn from(value: RangeInclusive<i64>) -> Self {
+        Self::new(*value.start(), *value.end())
+    }
+}
+
+impl From<&[i64]> for Domain {
+    fn from(values: &[i64]) -> Self {
+        Self::from_values(values)
+    }
+}
+
+impl FromIterator<i64> for Domain {
+    fn from_iter<T: IntoIterator<Item = i64>>(iter: T) -> Self {
+        let values: Vec<_> = iter.into_iter().collect();
+        Self::from_values(&values)
+    }
+}
+
+impl<'a> IntoIterator for &'a Domain {
+    type Item = RangeInclusive<i64>;
+    type IntoIter = Intervals<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.intervals()
+    }
+}
+
+/// An iterator over the intervals of a `Domain`, in increasing order.
+pub struct Intervals<'domain> {
+    domain: &'domain Domain,
+    front: c_int,
+    back: c_int,
+}
+
+impl<'domain> Iterator for Intervals<'domain> {
+    type Item = RangeInclusive<i64>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            let interval = self.domain.interval(self.front);
+            self.front += 1;
+            Some(interval)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back - self.front) as usize;
+        (len, Some(len))
+    }
+}
+
+impl<'domain> DoubleEndedIterator for Intervals<'domain> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+            Some(self.domain.interval(self.back))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'domain> ExactSizeIterator for Intervals<'domain> {}
+
+impl<'domain> FusedIterator for Intervals<'domain> {}
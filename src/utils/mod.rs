@@ -1 +1,2 @@
 pub mod guard;
+pub(crate) mod string;
@@ -0,0 +1,57 @@
+use std::{fmt, slice};
+
+// IMPORT CXX LIBRARY
+cpp! {{
+    #include <memory>
+    #include <string>
+}}
+
+cpp_class!(
+    /// A `std::string` returned from the C++ library.
+    ///
+    /// The string is kept behind a `std::unique_ptr`, as `std::string` itself is
+    /// not relocatable (small-string optimization).
+    pub unsafe struct CxxString as "std::unique_ptr<std::string>"
+);
+
+impl CxxString {
+    /// Returns the raw bytes of the string.
+    pub fn as_bytes(&self) -> &[u8] {
+        let len = unsafe {
+            cpp!([
+                self as "const std::unique_ptr<std::string>*"
+            ] -> usize as "size_t"
+                {
+                    return *self ? (*self)->size() : 0;
+                }
+            )
+        };
+        if len == 0 {
+            return &[];
+        }
+
+        let data = unsafe {
+            cpp!([
+                self as "const std::unique_ptr<std::string>*"
+            ] -> *const u8 as "const char*"
+                {
+                    return (*self)->data();
+                }
+            )
+        };
+
+        unsafe { slice::from_raw_parts(data, len) }
+    }
+}
+
+impl fmt::Display for CxxString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        String::from_utf8_lossy(self.as_bytes()).fmt(f)
+    }
+}
+
+impl From<CxxString> for String {
+    fn from(value: CxxString) -> Self {
+        String::from_utf8_lossy(value.as_bytes()).into_owned()
+    }
+}
@@ -0,0 +1,167 @@
+use std::process::Command;
+
+use or_tools::flatzinc::{
+    cp_model_fz_solver::{solve_fz_with_cp_model, FlatzincSatParameters},
+    parser::parse_flatzinc_string,
+};
+
+fn solve(fzn: &str, parameters: &FlatzincSatParameters) -> String {
+    let model = parse_flatzinc_string(fzn).expect("failed to parse FlatZinc");
+    let mut output = Vec::new();
+    solve_fz_with_cp_model(&model, parameters, &mut output).expect("failed to solve");
+    let output = String::from_utf8(output).unwrap();
+    println!("{output}");
+    output
+}
+
+#[test]
+fn solve_optimization() {
+    let fzn = "\
+predicate fzn_all_different_int(array [int] of var int: x);
+array [1..2] of int: X_INTRODUCED_2_ = [1, 1];
+var 0..10: x :: output_var;
+var 0..10: y :: output_var;
+var bool: b :: output_var;
+var 0..20: z :: is_defined_var;
+array [1..3] of var int: xs :: output_array([1..3]) = [x, y, 7];
+constraint int_lin_le(X_INTRODUCED_2_, [x, y], 12);
+constraint int_lin_eq([1, 1, -1], [x, y, z], 0) :: defines_var(z);
+constraint int_le_reif(x, 4, b);
+constraint int_lt(y, x);
+solve :: int_search(xs, input_order, indomain_min, complete) maximize z;
+";
+
+    let output = solve(fzn, &FlatzincSatParameters::default());
+    let mut lines = output.lines();
+    assert_eq!(lines.next_back(), Some("=========="));
+    assert_eq!(lines.next_back(), Some("----------"));
+    assert!(output.contains("b = false;\n"));
+    assert!(output.contains("xs = array1d(1..3, ["));
+}
+
+#[test]
+fn solve_all_solutions() {
+    let fzn = "\
+var 1..3: x :: output_var;
+var 1..3: y :: output_var;
+constraint int_lt(x, y);
+solve satisfy;
+";
+
+    let parameters = FlatzincSatParameters {
+        display_all_solutions: true,
+        ..Default::default()
+    };
+    let output = solve(fzn, &parameters);
+    assert_eq!(output.matches("----------\n").count(), 3);
+    assert!(output.contains("x = 1;\ny = 3;\n----------\n"));
+    assert!(output.ends_with("==========\n"));
+
+    // Without `-a`, a satisfaction problem stops at the first solution.
+    let output = solve(fzn, &FlatzincSatParameters::default());
+    assert_eq!(output.matches("----------\n").count(), 1);
+    assert!(!output.contains("=========="));
+}
+
+#[test]
+fn solve_unsatisfiable() {
+    let fzn = "\
+var bool: a :: output_var;
+var bool: b;
+constraint bool_clause([a], [b]);
+constraint bool_clause([b], []);
+constraint bool_not(a, a);
+solve satisfy;
+";
+
+    let output = solve(fzn, &FlatzincSatParameters::default());
+    assert_eq!(output, "=====UNSATISFIABLE=====\n");
+}
+
+#[test]
+fn solve_builtins() {
+    let fzn = "\
+var 0..3: x :: output_var;
+var 0..30: y :: output_var;
+var bool: a :: output_var;
+var bool: b :: output_var;
+var bool: r :: output_var;
+constraint int_pow(x, 3, y);
+constraint int_le(20, y);
+constraint bool_clause_reif([a], [b], r);
+constraint bool_eq(b, true);
+constraint bool_eq(r, false);
+solve satisfy;
+";
+
+    let output = solve(fzn, &FlatzincSatParameters::default());
+    assert_eq!(
+        output,
+        "x = 3;\ny = 27;\na = false;\nb = true;\nr = false;\n----------\n",
+    );
+}
+
+/// Runs the `or-tools-fzn` binary on a FlatZinc model.
+fn run_fzn(fzn: &str, args: &[&str]) -> std::process::Output {
+    // Named after the process so that concurrent runs do not race
+    let path =
+        std::env::temp_dir().join(format!("or-tools-fzn-{pid}.fzn", pid = std::process::id(),));
+    std::fs::write(&path, fzn).expect("failed to write FlatZinc");
+    let output = Command::new(env!("CARGO_BIN_EXE_or-tools-fzn"))
+        .args(args)
+        .arg(&path)
+        .output()
+        .expect("failed to run or-tools-fzn");
+    std::fs::remove_file(&path).ok();
+    println!("{}", String::from_utf8_lossy(&output.stdout));
+    output
+}
+
+#[test]
+fn run_with_workers_and_time_limit() {
+    let fzn = "\
+var 0..10: x :: output_var;
+var 0..10: y :: output_var;
+constraint int_lin_le([1, 1], [x, y], 12);
+solve maximize x;
+";
+
+    // `-p` sets the number of workers.
+    let output = run_fzn(fzn, &["-p", "2"]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.starts_with("x = 10;\n"));
+    assert!(stdout.ends_with("----------\n==========\n"));
+
+    // `-t` sets a time limit in milliseconds, long enough to prove optimality.
+    let output = run_fzn(fzn, &["-t", "10000", "-p", "1"]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.ends_with("----------\n==========\n"));
+
+    // Both expect a non-negative integer.
+    for args in [["-p", "-1"], ["-t", "1.5"]] {
+        let output = run_fzn(fzn, &args);
+        assert_eq!(output.status.code(), Some(2));
+    }
+}
+
+#[test]
+fn reject_unsupported_models() {
+    assert!(parse_flatzinc_string("var 1..3: x;").is_err());
+
+    let model = parse_flatzinc_string("var float: x;\nsolve satisfy;\n").unwrap();
+    let mut output = Vec::new();
+    assert!(
+        solve_fz_with_cp_model(&model, &FlatzincSatParameters::default(), &mut output).is_err()
+    );
+
+    let model = parse_flatzinc_string(
+        "var 0..1: x;\nvar 0..1: y;\nconstraint int_pow(x, 1000000000, y);\nsolve satisfy;\n",
+    )
+    .unwrap();
+    let mut output = Vec::new();
+    assert!(
+        solve_fz_with_cp_model(&model, &FlatzincSatParameters::default(), &mut output).is_err()
+    );
+}
@@ -0,0 +1,43 @@
+use itertools::Itertools;
+use or_tools::sat::cp_model::{CpModelBuilder, CpSolverStatus};
+
+#[test]
+fn assumptions_sample_sat() {
+    let cp_model = CpModelBuilder::new();
+
+    // Main variables.
+    let x = cp_model.new_int_var(0..=10).with_name("x");
+    let y = cp_model.new_int_var(0..=10).with_name("y");
+    let z = cp_model.new_int_var(0..=10).with_name("z");
+    let a = cp_model.new_bool_var().with_name("a");
+    let b = cp_model.new_bool_var().with_name("b");
+    let c = cp_model.new_bool_var().with_name("c");
+
+    cp_model.add_linear_gt(x, y).only_enforce_if(&[a]);
+    cp_model.add_linear_gt(y, z).only_enforce_if(&[b]);
+    cp_model.add_linear_gt(z, x).only_enforce_if(&[c]);
+
+    // Add assumptions
+    cp_model.add_assumptions(&[a, b, c]);
+
+    // Solving part.
+    let response = cp_model.solve();
+
+    // Print solution.
+    println!("Status: {:?}", response.status());
+    let core = response.sufficient_assumptions_for_infeasibility(&cp_model);
+    println!("{}", core.iter().map(|literal| literal.name()).join(", "));
+
+    assert_eq!(response.status(), CpSolverStatus::Infeasible);
+    assert_eq!(
+        core.iter()
+            .map(|literal| literal.index())
+            .sorted()
+            .collect_vec(),
+        [a.index(), b.index(), c.index()],
+    );
+
+    // Without the assumptions, the model becomes feasible again.
+    cp_model.clear_assumptions();
+    assert_eq!(cp_model.solve().status(), CpSolverStatus::Optimal);
+}
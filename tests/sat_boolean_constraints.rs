@@ -0,0 +1,74 @@
+use or_tools::sat::cp_model::{CpModelBuilder, CpSolverStatus, IntVar};
+
+#[test]
+fn literal_sample_sat() {
+    let cp_model = CpModelBuilder::new();
+
+    let x = cp_model.new_bool_var().with_name("x");
+    let not_x = !x;
+    println!("{} -> index {}", x.name(), x.index());
+    println!("not({}) -> index {}", x.name(), not_x.index());
+
+    assert_eq!(x.name(), "x");
+    assert_eq!(not_x.index(), -x.index() - 1);
+    assert_eq!((!not_x).index(), x.index());
+}
+
+#[test]
+#[should_panic(expected = "cannot be cast into an integer variable")]
+fn negated_literal_into_int_var() {
+    let cp_model = CpModelBuilder::new();
+
+    let x = cp_model.new_bool_var();
+    let _ = IntVar::from(!x);
+}
+
+#[test]
+fn bool_or_sample_sat() {
+    let cp_model = CpModelBuilder::new();
+
+    let x = cp_model.new_bool_var().with_name("x");
+    let y = cp_model.new_bool_var().with_name("y");
+
+    cp_model.add_bool_or(&[x, !y]);
+    cp_model.add_bool_and(&[!x]);
+
+    // Solving part.
+    let response = cp_model.solve();
+    println!("x = {}", response.boolean_value(&x));
+    println!("y = {}", response.boolean_value(&y));
+
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert!(!response.boolean_value(&x));
+    assert!(!response.boolean_value(&y));
+    assert!(response.boolean_value(&!y));
+}
+
+#[test]
+fn boolean_constraints_sample_sat() {
+    let cp_model = CpModelBuilder::new();
+
+    let a = cp_model.new_bool_var().with_name("a");
+    let b = cp_model.new_bool_var().with_name("b");
+    let c = cp_model.new_bool_var().with_name("c");
+    let d = cp_model.new_bool_var().with_name("d");
+
+    cp_model.add_exactly_one(&[a, b, c]);
+    // `a` would need `b` as well, which breaks the exactly-one constraint.
+    cp_model.add_implication(a, b);
+    // `b` would leave `c` false and then need `d` by the xor constraint, which
+    // breaks the at-most-one constraint.
+    cp_model.add_at_most_one(&[b, d]);
+    cp_model.add_bool_xor(&[c, d]);
+
+    cp_model.maximize(4 * a + 2 * b + c + d);
+
+    // Solving part.
+    let response = cp_model.solve();
+    let values = [a, b, c, d].map(|literal| response.boolean_value(&literal));
+    println!("a, b, c, d = {values:?}");
+
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert_eq!(response.objective_value(), 1.0);
+    assert_eq!(values, [false, false, true, false]);
+}
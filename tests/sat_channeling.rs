@@ -0,0 +1,48 @@
+use or_tools::sat::cp_model::{CpModelBuilder, CpSolverStatus};
+
+#[test]
+fn channeling_sample_sat() {
+    for value in 0..=10 {
+        let cp_model = CpModelBuilder::new();
+
+        // Declare our two primary variables.
+        let x = cp_model.new_int_var(0..=10).with_name("x");
+        let y = cp_model.new_int_var(0..=10).with_name("y");
+
+        // Declare our intermediate boolean variable.
+        let b = cp_model.new_bool_var().with_name("b");
+
+        // Implement b == (x >= 5).
+        cp_model.add_linear_ge(x, 5).only_enforce_if(&[b]);
+        cp_model.add_linear_lt(x, 5).only_enforce_if(&[!b]);
+
+        // Create our two half-reified constraints.
+        // First, b implies (y == 10 - x).
+        let on = cp_model
+            .add_linear_eq(x + y, 10)
+            .only_enforce_if(&[b])
+            .with_name("on");
+        // Second, not(b) implies y == 0.
+        let off = cp_model
+            .add_linear_eq(y, 0)
+            .only_enforce_if(&[!b])
+            .with_name("off");
+        assert_eq!(on.name(), "on");
+        assert_eq!(off.name(), "off");
+
+        cp_model.add_linear_eq(x, value);
+
+        // Solving part.
+        let response = cp_model.solve();
+        println!(
+            "x={} y={} b={}",
+            response.value(&x),
+            response.value(&y),
+            response.boolean_value(&b),
+        );
+
+        assert_eq!(response.status(), CpSolverStatus::Optimal);
+        assert_eq!(response.boolean_value(&b), value >= 5);
+        assert_eq!(response.value(&y), if value >= 5 { 10 - value } else { 0 },);
+    }
+}
@@ -0,0 +1,109 @@
+use or_tools::sat::{
+    cp_model::{CpModelBuilder, CpSolverStatus},
+    sat_cnf_reader::CnfProblem,
+};
+
+#[test]
+fn solve_cnf() {
+    let cnf = "\
+c A simple satisfiable problem
+p cnf 3 4
+1 2 0
+-1 3 0
+-3 -2
+0
+-2 0
+";
+
+    let model = CpModelBuilder::new();
+    let problem = CnfProblem::load(&model, cnf.as_bytes()).expect("failed to parse CNF");
+    assert_eq!(problem.variables().len(), 3);
+    assert!(!problem.is_weighted());
+
+    let response = model.solve();
+    let mut output = Vec::new();
+    problem
+        .write_solution(&response, &mut output)
+        .expect("failed to write solution");
+    let output = String::from_utf8(output).unwrap();
+    println!("{output}");
+
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert_eq!(output, "s SATISFIABLE\nv 1 -2 3 0\n");
+}
+
+#[test]
+fn solve_unsatisfiable_cnf() {
+    let cnf = "p cnf 1 2\n1 0\n-1 0\n";
+
+    let model = CpModelBuilder::new();
+    let problem = CnfProblem::load(&model, cnf.as_bytes()).expect("failed to parse CNF");
+
+    let response = model.solve();
+    let mut output = Vec::new();
+    problem
+        .write_solution(&response, &mut output)
+        .expect("failed to write solution");
+
+    assert_eq!(String::from_utf8(output).unwrap(), "s UNSATISFIABLE\n");
+    assert!(CnfProblem::load(&model, "p cnf 1 1\n2 0\n".as_bytes()).is_err());
+
+    // Without header, a huge literal is rejected instead of creating that many
+    // variables.
+    let model = CpModelBuilder::new();
+    let error = CnfProblem::load(&model, "h 1 -2000000000 0\n".as_bytes())
+        .err()
+        .expect("huge literals should be rejected");
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    assert!(model.num_variables() <= 1);
+
+    // So are huge or negative numbers of variables in the header, and soft
+    // weights whose sum overflows.
+    for invalid in [
+        "p cnf 2000000000 1\n1 0\n",
+        "p cnf -1 1\n",
+        "p wcnf 1 2\n9223372036854775807 1 0\n1 -1 0\n",
+    ] {
+        let model = CpModelBuilder::new();
+        assert!(CnfProblem::load(&model, invalid.as_bytes()).is_err());
+        assert!(model.num_variables() <= 1);
+    }
+}
+
+#[test]
+fn solve_wcnf() {
+    for wcnf in [
+        // With the "top" weight for hard clauses.
+        "\
+p wcnf 3 5 100
+100 1 2 0
+100 -1 -2 0
+4 1 0
+3 2 3 0
+2 -3 0
+",
+        // Without header.
+        "\
+h 1 2 0
+h -1 -2 0
+4 1 0
+3 2 3 0
+2 -3 0
+",
+    ] {
+        let model = CpModelBuilder::new();
+        let problem = CnfProblem::load(&model, wcnf.as_bytes()).expect("failed to parse WCNF");
+        assert!(problem.is_weighted());
+
+        let response = model.solve();
+        let mut output = Vec::new();
+        problem
+            .write_solution(&response, &mut output)
+            .expect("failed to write solution");
+        let output = String::from_utf8(output).unwrap();
+        println!("{output}");
+
+        assert_eq!(response.status(), CpSolverStatus::Optimal);
+        assert_eq!(output, "s OPTIMUM FOUND\no 2\nv 1 -2 3\n");
+    }
+}
@@ -0,0 +1,60 @@
+use or_tools::{
+    cp_model,
+    sat::cp_model::{CpModelBuilder, CpSolverStatus},
+};
+
+#[test]
+fn cp_model_macro_sample_sat() {
+    let model = CpModelBuilder::new();
+    let sum = 12;
+
+    cp_model! { model =>
+        x, y, z in 0..10;
+        w in [1, 3, 5];
+        b: bool;
+
+        all_different([x, y, z]);
+        x + y + z == {sum};
+        2 * x - y >= -(z - 3);
+        z != w;
+        x <= 5;
+        implication(b, !b);
+        minimize x + 2 * y + 3 * w;
+    }
+
+    let response = model.solve();
+    println!("{response:?}");
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+
+    let (x, y, z, w) = (
+        response.value(&x),
+        response.value(&y),
+        response.value(&z),
+        response.value(&w),
+    );
+    println!("x = {x}, y = {y}, z = {z}, w = {w}");
+    assert_eq!(x + y + z, sum);
+    assert!(x != y && y != z && x != z);
+    assert!(2 * x - y >= 3 - z);
+    assert!(!response.boolean_value(&b));
+    assert_eq!(response.objective_value(), 5.0);
+}
+
+#[test]
+fn cp_model_macro_boolean_constraints() {
+    let model = CpModelBuilder::new();
+
+    cp_model! { &model =>
+        a, b, c: bool;
+        bool_or([a, b]);
+        at_most_one([a, b, c]);
+        exactly_one([b, c]);
+        !a;
+    }
+
+    let response = model.solve();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert!(!response.boolean_value(&a));
+    assert!(response.boolean_value(&b));
+    assert!(!response.boolean_value(&c));
+}
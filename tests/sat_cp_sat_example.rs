@@ -0,0 +1,44 @@
+use or_tools::sat::cp_model::{CpModelBuilder, CpSolverStatus};
+
+#[test]
+fn cp_sat_example() {
+    let cp_model = CpModelBuilder::new();
+
+    let var_upper_bound = [50, 45, 37].into_iter().max().unwrap();
+    let domain = 0..=var_upper_bound;
+    let x = cp_model.new_int_var(domain.clone()).with_name("x");
+    let y = cp_model.new_int_var(domain.clone()).with_name("y");
+    let z = cp_model.new_int_var(domain).with_name("z");
+
+    cp_model.add_linear_le(2 * x + 7 * y + 3 * z, 50);
+    cp_model.add_linear_le(3 * x - 5 * y + 7 * z, 45);
+    cp_model.add_linear_le(5 * x + 2 * y - 6 * z, 37);
+
+    cp_model.maximize(2 * x + 2 * y + 3 * z);
+
+    // Solving part.
+    let response = cp_model.solve();
+
+    let status = response.status();
+    if status == CpSolverStatus::Optimal || status == CpSolverStatus::Feasible {
+        println!(
+            "Maximum of objective function: {}",
+            response.objective_value()
+        );
+        println!("x = {}", response.value(&x));
+        println!("y = {}", response.value(&y));
+        println!("z = {}", response.value(&z));
+    } else {
+        println!("No solution found.");
+    }
+
+    // Statistics.
+    println!("Statistics");
+    println!("  conflicts: {}", response.num_conflicts());
+    println!("  branches : {}", response.num_branches());
+    println!("  wall time: {}s", response.wall_time());
+
+    assert_eq!(status, CpSolverStatus::Optimal);
+    assert_eq!(response.objective_value(), 35.0);
+    assert_eq!(response.evaluate(2 * x + 2 * y + 3 * z), 35);
+}
@@ -0,0 +1,42 @@
+use or_tools::sat::{
+    cp_model::{
+        CpModelBuilder, CpSolverStatus, DomainReductionStrategy, VariableSelectionStrategy,
+    },
+    sat_parameters::SatParameters,
+};
+
+#[test]
+fn fixed_search_sample_sat() {
+    let cp_model = CpModelBuilder::new();
+
+    let domain = 0..=3;
+    let x = cp_model.new_int_var(domain.clone()).with_name("x");
+    let y = cp_model.new_int_var(domain.clone()).with_name("y");
+    let z = cp_model.new_int_var(domain).with_name("z");
+
+    cp_model.add_all_different([x, y, z]);
+
+    cp_model.add_decision_strategy(
+        [x, y, z],
+        VariableSelectionStrategy::ChooseFirst,
+        DomainReductionStrategy::SelectMaxValue,
+    );
+
+    // Follow the decision strategy only.
+    let mut parameters = SatParameters::new();
+    parameters.set_num_workers(1);
+    parameters
+        .merge_from_text("search_branching: FIXED_SEARCH")
+        .expect("failed to parse parameters");
+
+    let response = cp_model.solve_with_parameters(&parameters);
+    println!("  x = {}", response.value(&x));
+    println!("  y = {}", response.value(&y));
+    println!("  z = {}", response.value(&z));
+
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert_eq!(
+        [response.value(&x), response.value(&y), response.value(&z)],
+        [3, 2, 1],
+    );
+}
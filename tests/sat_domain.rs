@@ -0,0 +1,85 @@
+use itertools::Itertools;
+use or_tools::{
+    sat::cp_model::{CpModelBuilder, CpSolverStatus},
+    util::sorted_interval_list::Domain,
+};
+
+#[test]
+fn domain_set_operations() {
+    let domain = Domain::from_intervals(&[0..=3, 6..=9]);
+    println!("{domain:?}");
+
+    assert_eq!(domain.num_intervals(), 2);
+    assert_eq!(domain.intervals().collect_vec(), [0..=3, 6..=9]);
+    assert_eq!(domain.flattened_intervals(), [0, 3, 6, 9]);
+    assert_eq!(domain.size(), 8);
+    assert_eq!(domain.min(), Some(0));
+    assert_eq!(domain.max(), Some(9));
+    assert!(domain.contains(6));
+    assert!(!domain.contains(4));
+    assert!(!domain.is_fixed());
+
+    let other = Domain::new(2, 7);
+    assert_eq!(
+        domain.intersection(&other),
+        Domain::from_intervals(&[2..=3, 6..=7]),
+    );
+    assert_eq!(domain.union(&other), Domain::new(0, 9));
+    assert_eq!(
+        domain.addition(&Domain::from_value(10)),
+        Domain::from_intervals(&[10..=13, 16..=19]),
+    );
+    assert_eq!(
+        domain.negation(),
+        Domain::from_intervals(&[-9..=-6, -3..=0])
+    );
+    assert_eq!(
+        domain.complement().intersection(&Domain::new(0, 9)),
+        Domain::new(4, 5),
+    );
+    assert_eq!(domain.complement().complement(), domain);
+    assert!(domain.intersection(&other).is_included_in(&domain));
+    assert!(!other.is_included_in(&domain));
+
+    // Values are sorted and merged into intervals.
+    assert_eq!(
+        Domain::from_values(&[5, 1, 2, 2, 3]),
+        Domain::from_intervals(&[1..=3, 5..=5]),
+    );
+    assert_eq!(
+        [3, 1, 2, 5].into_iter().collect::<Domain>().num_intervals(),
+        2
+    );
+    assert_eq!(Domain::from_flat_intervals(&[0, 3, 6, 9]), domain);
+
+    // Empty and fixed domains.
+    let empty = Domain::new(1, 0);
+    assert!(empty.is_empty());
+    assert_eq!(empty.min(), None);
+    assert_eq!(empty.size(), 0);
+    assert!(Domain::from_value(5).is_fixed());
+    assert!(Domain::all_values().contains(i64::MIN));
+}
+
+#[test]
+fn domain_with_holes_sample_sat() {
+    let cp_model = CpModelBuilder::new();
+
+    let domain = Domain::from_intervals(&[0..=2, 5..=6, 10..=10]);
+    let x = cp_model.new_int_var(domain.clone()).with_name("x");
+    let y = cp_model.new_int_var(0..=10).with_name("y");
+
+    cp_model.add_linear_ge(x, 3);
+    cp_model.add_linear_in_domain(x + y, Domain::from_values(&[8, 13]));
+    cp_model.minimize(x);
+
+    // Solving part.
+    let response = cp_model.solve();
+    println!("x = {}", response.value(&x));
+    println!("y = {}", response.value(&y));
+
+    assert_eq!(x.domain(), domain);
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert_eq!(response.value(&x), 5);
+    assert_eq!(response.value(&y), 3);
+}
@@ -0,0 +1,109 @@
+use itertools::Itertools;
+use or_tools::sat::cp_model::{CpModelBuilder, CpSolverStatus, LinearExpr};
+
+#[test]
+fn element_sample_sat() {
+    let cp_model = CpModelBuilder::new();
+
+    let values = [10, 20, 30];
+    let index = cp_model.new_int_var(0..=2).with_name("index");
+    let target = cp_model.new_int_var(0..=100).with_name("target");
+    cp_model.add_element(index, &values, target);
+
+    let variables = [
+        cp_model.new_int_var(0..=5),
+        cp_model.new_int_var(7..=7),
+        cp_model.new_int_var(2..=3),
+    ];
+    let variable_target = cp_model.new_int_var(0..=10).with_name("variable_target");
+    cp_model.add_variable_element(index, &variables, variable_target);
+
+    cp_model.add_linear_ge(target, 15);
+    cp_model.add_linear_le(target, 25);
+
+    // Solving part.
+    let response = cp_model.solve();
+    println!("index = {}", response.value(&index));
+    println!("target = {}", response.value(&target));
+
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert_eq!(response.value(&index), 1);
+    assert_eq!(response.value(&target), 20);
+    assert_eq!(response.value(&variable_target), 7);
+}
+
+#[test]
+fn circuit_sample_sat() {
+    let cp_model = CpModelBuilder::new();
+
+    // Arcs are stored as (tail, head, cost).
+    let arcs = [
+        (0, 1, 1),
+        (1, 2, 1),
+        (2, 0, 1),
+        (0, 2, 5),
+        (2, 1, 5),
+        (1, 0, 5),
+    ];
+    let literals = arcs.map(|_| cp_model.new_bool_var());
+
+    let mut circuit = cp_model.add_circuit();
+    for ((tail, head, _), literal) in arcs.iter().zip(literals) {
+        circuit.add_arc(*tail, *head, literal);
+    }
+
+    cp_model.minimize(
+        arcs.iter()
+            .zip(literals)
+            .map(|((_, _, cost), literal)| *cost * literal)
+            .sum::<LinearExpr>(),
+    );
+
+    // Solving part.
+    let response = cp_model.solve();
+    let selected = arcs
+        .iter()
+        .zip(literals)
+        .filter(|(_, literal)| response.boolean_value(literal))
+        .map(|((tail, head, _), _)| (*tail, *head))
+        .collect_vec();
+    println!("Tour: {selected:?}");
+
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert_eq!(response.objective_value(), 3.0);
+    assert_eq!(selected, [(0, 1), (1, 2), (2, 0)]);
+}
+
+#[test]
+fn table_and_inverse_sample_sat() {
+    let cp_model = CpModelBuilder::new();
+
+    let x = cp_model.new_int_var(0..=10).with_name("x");
+    let y = cp_model.new_int_var(0..=10).with_name("y");
+
+    let mut allowed = cp_model.add_allowed_assignments(&[x, y]);
+    allowed.add_tuple(&[1, 2]);
+    allowed.add_tuple(&[3, 4]);
+    allowed.add_tuple(&[5, 6]);
+
+    let mut forbidden = cp_model.add_forbidden_assignments(&[x, y]);
+    forbidden.add_tuple(&[5, 6]);
+
+    // f and g are permutations of {0, 1, 2} inverse of each other.
+    let f = [0, 1, 2].map(|_| cp_model.new_int_var(0..=2));
+    let g = [0, 1, 2].map(|_| cp_model.new_int_var(0..=2));
+    cp_model.add_inverse(&f, &g);
+    cp_model.add_linear_eq(f[0], 1);
+    cp_model.add_linear_eq(f[1], 2);
+
+    cp_model.maximize(x);
+
+    // Solving part.
+    let response = cp_model.solve();
+    println!("x = {}", response.value(&x));
+    println!("y = {}", response.value(&y));
+
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert_eq!([response.value(&x), response.value(&y)], [3, 4]);
+    assert_eq!(g.map(|var| response.value(&var)), [2, 0, 1]);
+}
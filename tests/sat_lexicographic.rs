@@ -0,0 +1,92 @@
+use or_tools::sat::{
+    cp_model::{CpModelBuilder, CpSolverStatus, LinearExpr},
+    lexicographic::{solve_lexicographic, LexicographicObjective},
+    sat_parameters::SatParameters,
+};
+
+#[test]
+fn solve_lexicographic_sample_sat() {
+    let cp_model = CpModelBuilder::new();
+
+    let x = cp_model.new_int_var(0..=10).with_name("x");
+    let y = cp_model.new_int_var(0..=10).with_name("y");
+    let z = cp_model.new_int_var(0..=10).with_name("z");
+    cp_model.add_linear_le(LinearExpr::from(x) + y + z, 15);
+
+    let objectives = [
+        // Coverage first, with some slack for the next objectives.
+        LexicographicObjective::maximize(LinearExpr::from(x) + y + z).with_tolerance(1),
+        // Then fairness.
+        LexicographicObjective::minimize(z),
+        // Then preferences.
+        LexicographicObjective::maximize(x),
+    ];
+    let response = solve_lexicographic(&cp_model, &objectives, &SatParameters::new());
+
+    for (index, stage) in response.stages().iter().enumerate() {
+        println!(
+            "Stage {index}: {:?}, objective = {}, wall time = {}s",
+            stage.status(),
+            stage.objective_value(),
+            stage.wall_time(),
+        );
+    }
+    assert_eq!(response.stages().len(), 3);
+    assert_eq!(response.stages()[0].objective_value(), 15.0);
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+
+    let solution = response.response().unwrap();
+    assert_eq!(solution.value(&x), 10);
+    assert_eq!(solution.value(&z), 0);
+    assert!((4..=5).contains(&solution.value(&y)));
+
+    // The original model is left untouched.
+    assert!(!cp_model.has_objective());
+    assert_eq!(cp_model.num_constraints(), 1);
+}
+
+#[test]
+fn solve_lexicographic_infeasible() {
+    let cp_model = CpModelBuilder::new();
+
+    let x = cp_model.new_int_var(0..=10);
+    cp_model.add_linear_gt(x, 10);
+
+    let objectives = [
+        LexicographicObjective::minimize(x),
+        LexicographicObjective::maximize(x),
+    ];
+    let response = solve_lexicographic(&cp_model, &objectives, &SatParameters::new());
+    println!("{response:?}");
+
+    assert_eq!(response.stages().len(), 1);
+    assert_eq!(response.status(), CpSolverStatus::Infeasible);
+    assert!(response.response().is_none());
+}
+
+#[test]
+fn solve_lexicographic_with_huge_tolerance() {
+    let cp_model = CpModelBuilder::new();
+
+    let x = cp_model.new_int_var(-10..=10);
+    let y = cp_model.new_int_var(0..=10);
+
+    // The tolerance does not overflow the bound of the first objective.
+    let objectives = [
+        LexicographicObjective::minimize(x).with_tolerance(i64::MAX),
+        LexicographicObjective::maximize(LinearExpr::from(x) + y),
+    ];
+    let response = solve_lexicographic(&cp_model, &objectives, &SatParameters::new());
+
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert_eq!(response.stages()[1].objective_value(), 20.0);
+}
+
+#[test]
+#[should_panic(expected = "must be non-negative")]
+fn reject_negative_tolerance() {
+    let cp_model = CpModelBuilder::new();
+
+    let x = cp_model.new_int_var(0..=10);
+    LexicographicObjective::minimize(x).with_tolerance(-1);
+}
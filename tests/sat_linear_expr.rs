@@ -0,0 +1,82 @@
+use or_tools::sat::cp_model::{CpModelBuilder, CpSolverStatus, DoubleLinearExpr, LinearExpr};
+
+#[test]
+fn linear_expr_sample_sat() {
+    let cp_model = CpModelBuilder::new();
+
+    let x = cp_model.new_int_var(0..=10).with_name("x");
+    let y = cp_model.new_int_var(0..=10).with_name("y");
+    let b = cp_model.new_bool_var().with_name("b");
+
+    // Build an expression with the operators.
+    let expr = 2 * x + y - 3 + b;
+    println!("{expr:?}");
+
+    assert_eq!(expr.variables(), [x.index(), y.index(), b.index()]);
+    assert_eq!(expr.coefficients(), [2, 1, 1]);
+    assert_eq!(expr.constant(), -3);
+    assert!(!expr.is_constant());
+    assert!(LinearExpr::from(7).is_constant());
+    assert!(LinearExpr::from(7).variables().is_empty());
+    assert!(LinearExpr::from(7).coefficients().is_empty());
+
+    // Build the same kind of expressions with the helpers.
+    let sum: LinearExpr = [x, y].into_iter().sum();
+    assert_eq!(sum.variables(), LinearExpr::sum(&[x, y]).variables());
+    assert_eq!(sum.coefficients(), [1, 1]);
+
+    let weighted_sum = LinearExpr::weighted_sum(&[x, y], &[3, 2]);
+    assert_eq!(weighted_sum.coefficients(), [3, 2]);
+    assert_eq!((-LinearExpr::term(x, 4)).coefficients(), [-4]);
+
+    cp_model.add_linear_eq(weighted_sum, 12);
+    cp_model.add_linear_eq(sum, 5);
+    cp_model.add_linear_eq(b, 1);
+
+    // Solving part.
+    let response = cp_model.solve();
+    println!("x = {}", response.value(&x));
+    println!("y = {}", response.value(&y));
+
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert_eq!([response.value(&x), response.value(&y)], [2, 3]);
+    assert_eq!(response.evaluate(expr), 5);
+}
+
+#[test]
+fn double_linear_expr_sample_sat() {
+    let cp_model = CpModelBuilder::new();
+
+    let x = cp_model.new_int_var(0..=10).with_name("x");
+    let y = cp_model.new_int_var(0..=10).with_name("y");
+
+    let expr = 0.5 * x + 1.5 * y - 2.0;
+    println!("{expr:?}");
+
+    assert_eq!(expr.variables(), [x.index(), y.index()]);
+    assert_eq!(expr.coefficients(), [0.5, 1.5]);
+    assert_eq!(expr.constant(), -2.0);
+    assert_eq!(
+        DoubleLinearExpr::weighted_sum(&[x, y], &[0.5, 1.5]).coefficients(),
+        expr.coefficients(),
+    );
+
+    let empty = DoubleLinearExpr::weighted_sum(&[], &[]);
+    assert!(empty.variables().is_empty());
+    assert!(empty.coefficients().is_empty());
+
+    cp_model.add_linear_le(x + y, 7);
+    cp_model.add_linear_ge(x, 3);
+    cp_model.maximize(expr);
+
+    // Solving part.
+    let response = cp_model.solve();
+    println!(
+        "Maximum of objective function: {}",
+        response.objective_value()
+    );
+
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert_eq!([response.value(&x), response.value(&y)], [3, 4]);
+    assert_eq!(response.objective_value(), 5.5);
+}
@@ -0,0 +1,63 @@
+use or_tools::sat::cp_model::{CpModelBuilder, CpSolverStatus};
+
+#[test]
+fn export_and_import_model() {
+    let cp_model = CpModelBuilder::new();
+
+    let x = cp_model.new_int_var(0..=10).with_name("x");
+    let y = cp_model.new_int_var(0..=10).with_name("y");
+    let b = cp_model.new_bool_var().with_name("b");
+    let task = cp_model.new_fixed_size_interval_var(x, 3).with_name("task");
+
+    cp_model.add_linear_le(x + y, 12).with_name("capacity");
+    cp_model.add_linear_eq(y, 10).only_enforce_if(&[b]);
+    cp_model.maximize(x + 2 * y);
+
+    let expected = cp_model.solve();
+    assert_eq!(expected.status(), CpSolverStatus::Optimal);
+
+    // Binary format
+    let bytes = cp_model.export_to_bytes();
+    let model = CpModelBuilder::from_bytes(&bytes).expect("failed to parse binary model");
+    assert_eq!(model.solve().objective_value(), expected.objective_value());
+
+    // Text format
+    let text = cp_model.export_to_text();
+    println!("{text}");
+    let model = CpModelBuilder::from_text(&text).expect("failed to parse text model");
+    assert_eq!(model.solve().objective_value(), expected.objective_value());
+    assert!(CpModelBuilder::from_text("not a model").is_none());
+
+    // Files, named after the process so that concurrent runs do not race
+    let dir = std::env::temp_dir();
+    for extension in ["pb", "txt"] {
+        let path = dir.join(format!(
+            "or-tools-sat-model-{pid}.{extension}",
+            pid = std::process::id(),
+        ));
+        cp_model
+            .export_to_file(&path)
+            .expect("failed to export model");
+        let model = CpModelBuilder::from_file(&path).expect("failed to import model");
+        std::fs::remove_file(&path).ok();
+
+        // Recover the handles by name and by index.
+        let x = model.int_var_by_name("x").expect("no variable named x");
+        assert_eq!(x.index(), 0);
+        assert!(model.bool_var_by_name("x").is_none());
+        assert!(model.bool_var_from_index(b.index()).is_some());
+        assert!(model.int_var_from_index(model.num_variables()).is_none());
+        assert_eq!(
+            model.interval_var_by_name("task").map(|task| task.index()),
+            Some(task.index()),
+        );
+        let capacity = model
+            .constraint_by_name("capacity")
+            .expect("no constraint named capacity");
+        assert_eq!(capacity.name(), "capacity");
+        assert!(model.interval_var_by_name("capacity").is_none());
+
+        let response = model.solve();
+        assert_eq!(response.value(&x), expected.value(&x));
+    }
+}
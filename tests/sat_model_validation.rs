@@ -0,0 +1,30 @@
+use or_tools::{
+    sat::cp_model::{CpModelBuilder, CpSolverStatus},
+    util::sorted_interval_list::Domain,
+};
+
+#[test]
+fn validate_model() {
+    let cp_model = CpModelBuilder::new();
+
+    let x = cp_model.new_int_var(0..=10).with_name("x");
+    let y = cp_model.new_int_var(0..=10).with_name("y");
+    cp_model.add_linear_le(x + y, 12);
+    cp_model.maximize(x + 2 * y);
+
+    assert_eq!(cp_model.validate(), Ok(()));
+    println!("{}", cp_model.model_stats());
+
+    let response = cp_model.solve();
+    println!("{}", response.response_stats());
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+
+    // An empty domain is reported before solving.
+    cp_model.new_int_var(Domain::new(1, 0)).with_name("empty");
+
+    let error = cp_model
+        .validate()
+        .expect_err("the model should be invalid");
+    println!("{error}");
+    assert_eq!(cp_model.solve().status(), CpSolverStatus::ModelInvalid);
+}
@@ -0,0 +1,78 @@
+use or_tools::sat::cp_model::{CpModelBuilder, CpSolverStatus};
+
+#[test]
+fn division_and_modulo_sample_sat() {
+    let cp_model = CpModelBuilder::new();
+
+    let x = cp_model.new_int_var(-20..=20).with_name("x");
+    let quotient = cp_model.new_int_var(-10..=10).with_name("quotient");
+    let remainder = cp_model.new_int_var(-10..=10).with_name("remainder");
+
+    cp_model.add_division_equality(quotient, x, 3);
+    cp_model.add_modulo_equality(remainder, x, 3);
+    cp_model.add_linear_eq(x, -7);
+
+    // Solving part.
+    let response = cp_model.solve();
+    println!("x = {}", response.value(&x));
+    println!("x / 3 = {}", response.value(&quotient));
+    println!("x % 3 = {}", response.value(&remainder));
+
+    // Both are rounded towards zero, like in Rust.
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert_eq!(response.value(&quotient), -7 / 3);
+    assert_eq!(response.value(&remainder), -7 % 3);
+}
+
+#[test]
+fn abs_and_multiplication_sample_sat() {
+    let cp_model = CpModelBuilder::new();
+
+    let x = cp_model.new_int_var(-10..=10).with_name("x");
+    let y = cp_model.new_int_var(-10..=10).with_name("y");
+    let abs_x = cp_model.new_int_var(0..=10).with_name("abs_x");
+    let product = cp_model.new_int_var(-100..=100).with_name("product");
+
+    cp_model.add_abs_equality(abs_x, x);
+    cp_model.add_multiplication_equality(product, [x, y]);
+    cp_model.add_linear_eq(x + y, 1);
+
+    // The product is minimal at the boundary of the domains.
+    cp_model.minimize(product);
+
+    // Solving part.
+    let response = cp_model.solve();
+    println!("x = {}", response.value(&x));
+    println!("y = {}", response.value(&y));
+    println!("x * y = {}", response.value(&product));
+
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert_eq!(response.objective_value(), -90.0);
+    assert_eq!(response.value(&abs_x), response.value(&x).abs());
+    assert_eq!(
+        response.value(&product),
+        response.value(&x) * response.value(&y),
+    );
+}
+
+#[test]
+fn min_max_sample_sat() {
+    let cp_model = CpModelBuilder::new();
+
+    let vars = [2..=5, 4..=8, 3..=3].map(|domain| cp_model.new_int_var(domain));
+    let min = cp_model.new_int_var(0..=10).with_name("min");
+    let max = cp_model.new_int_var(0..=10).with_name("max");
+
+    cp_model.add_min_equality(min, vars);
+    cp_model.add_max_equality(max, vars);
+    cp_model.maximize(min - max);
+
+    // Solving part.
+    let response = cp_model.solve();
+    println!("min = {}", response.value(&min));
+    println!("max = {}", response.value(&max));
+
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert_eq!(response.value(&min), 3);
+    assert_eq!(response.value(&max), 4);
+}
@@ -0,0 +1,115 @@
+use std::io;
+
+use or_tools::sat::{
+    cp_model::{CpModelBuilder, CpSolverStatus},
+    opb_reader::{write_opb, OpbProblem},
+};
+
+#[test]
+fn solve_opb() {
+    let opb = "\
+* #variable= 4 #constraint= 3
+min: +1 x1 +2 x2 -1 x3 +3 ~x4 ;
++1 x1 +1 x2 >= 1 ;
++1 x2 +1 x3 +1 x4 <= 2;
+-1 x1 +2 x3 = 0 ;
+";
+
+    let model = CpModelBuilder::new();
+    let problem = OpbProblem::load(&model, opb.as_bytes()).expect("failed to parse OPB");
+    assert_eq!(problem.variables().len(), 4);
+
+    let response = model.solve();
+    let values = problem
+        .variables()
+        .iter()
+        .map(|var| response.boolean_value(var))
+        .collect::<Vec<_>>();
+    println!("objective: {}", response.objective_value());
+    println!("values: {values:?}");
+
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert_eq!(response.objective_value(), 2.0);
+    assert_eq!(values, [false, true, false, true]);
+
+    // Write the model back, and read it again.
+    let mut output = Vec::new();
+    write_opb(&model, &mut output).expect("failed to write OPB");
+    let output = String::from_utf8(output).unwrap();
+    println!("{output}");
+
+    let model = CpModelBuilder::new();
+    OpbProblem::load(&model, output.as_bytes()).expect("failed to parse written OPB");
+    let response = model.solve();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert_eq!(response.objective_value(), 2.0);
+}
+
+#[test]
+fn solve_wbo() {
+    let wbo = "\
+* #variable= 3 #constraint= 4 #soft= 3 mincost= 2 maxcost= 8 sumcost= 10
+soft: 10 ;
++1 x1 +1 x2 +1 x3 >= 2 ;
+[2] +1 x1 = 0 ;
+[5] +1 x2 = 0 ;
+[3] +1 x3 >= 1 ;
+";
+
+    let model = CpModelBuilder::new();
+    let problem = OpbProblem::load(&model, wbo.as_bytes()).expect("failed to parse WBO");
+    assert_eq!(problem.soft_constraints().len(), 3);
+
+    let response = model.solve();
+    let violated = problem
+        .soft_constraints()
+        .iter()
+        .map(|(violated, _)| response.boolean_value(violated))
+        .collect::<Vec<_>>();
+    println!("cost: {}", response.objective_value());
+    println!("violated: {violated:?}");
+
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert_eq!(response.objective_value(), 2.0);
+    assert_eq!(violated, [true, false, false]);
+
+    // Write the model back, and read it again.
+    let mut output = Vec::new();
+    write_opb(&model, &mut output).expect("failed to write WBO");
+    let output = String::from_utf8(output).unwrap();
+    println!("{output}");
+    assert!(output.contains("soft: 10 ;"));
+    assert!(output.contains("[5] +1 x2 = 0 ;"));
+
+    let model = CpModelBuilder::new();
+    let problem = OpbProblem::load(&model, output.as_bytes()).expect("failed to parse written WBO");
+    assert_eq!(problem.soft_constraints().len(), 3);
+    let response = model.solve();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert_eq!(response.objective_value(), 2.0);
+
+    // Products cannot be written in the OPB format.
+    let model = CpModelBuilder::new();
+    OpbProblem::load(&model, "min: +1 x1 x2 ;".as_bytes()).expect("failed to parse OPB");
+    let error = write_opb(&model, Vec::new()).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+}
+
+#[test]
+fn reject_out_of_range_literals() {
+    for opb in [
+        // Beyond the number of variables of the header.
+        "* #variable= 2 #constraint= 1\n+1 x1 +1 x3 >= 1 ;\n",
+        // A huge literal without header.
+        "+1 x1 +1 x2000000000 >= 1 ;\n",
+        // A huge number of variables in the header.
+        "* #variable= 2000000000 #constraint= 1\n+1 x1 >= 1 ;\n",
+    ] {
+        let model = CpModelBuilder::new();
+        let error = OpbProblem::load(&model, opb.as_bytes())
+            .err()
+            .expect("out of range literals should be rejected");
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(model.num_variables() <= 2);
+    }
+}
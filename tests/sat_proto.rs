@@ -0,0 +1,129 @@
+#![cfg(feature = "proto")]
+
+use or_tools::{
+    proto::{
+        prost::Message,
+        sat::{
+            constraint_proto::Constraint,
+            sat_parameters::{Polarity, RestartAlgorithm, SearchBranching},
+            CpModelProto, CpSolverStatus as CpSolverStatusProto,
+            SatParameters as SatParametersProto,
+        },
+    },
+    sat::{
+        cp_model::{CpModelBuilder, CpSolverResponse, CpSolverStatus, LinearExpr},
+        sat_parameters::SatParameters,
+    },
+};
+
+#[test]
+fn proto_model_round_trip() {
+    let cp_model = CpModelBuilder::new();
+
+    let x = cp_model.new_int_var(0..=10).with_name("x");
+    let y = cp_model.new_int_var(0..=10).with_name("y");
+    cp_model.add_all_different([x, y]);
+    cp_model.add_linear_le(LinearExpr::from(x) + y, 12);
+    cp_model.maximize(LinearExpr::from(x) + 2 * y);
+
+    // Inspect the model without the native library.
+    let proto = cp_model.export_to_proto();
+    println!("{proto:?}");
+    assert_eq!(proto.variables.len(), 2);
+    assert_eq!(proto.variables[0].name, "x");
+    assert_eq!(proto.variables[0].domain, [0, 10]);
+    assert_eq!(proto.constraints.len(), 2);
+    assert!(matches!(
+        proto.constraints[0].constraint,
+        Some(Constraint::AllDiff(_)),
+    ));
+    assert!(proto.objective.is_some());
+
+    // Ship it through the wire format, and solve it on the other side.
+    let bytes = proto.encode_to_vec();
+    let decoded = CpModelProto::decode(bytes.as_slice()).unwrap();
+    assert_eq!(decoded, proto);
+
+    let model = CpModelBuilder::from_proto(&decoded).unwrap();
+    assert_eq!(model.export_to_bytes(), cp_model.export_to_bytes());
+
+    let response = model.solve();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+
+    let response_proto = response.export_to_proto();
+    assert_eq!(response_proto.status(), CpSolverStatusProto::Optimal);
+    assert_eq!(response_proto.solution, response.solution());
+    assert_eq!(response_proto.objective_value, 22.0);
+
+    let response = CpSolverResponse::from_proto(&response_proto).unwrap();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert_eq!(response.value(&x), 2);
+    assert_eq!(response.value(&y), 10);
+
+    assert!(CpSolverResponse::from_bytes(b"\xff").is_none());
+}
+
+#[test]
+fn proto_encoding_matches_native() {
+    // The fields below are declared in the order of their tags, so that both
+    // serializers write them in the same order.
+    let cp_model = CpModelBuilder::new();
+
+    let x = cp_model.new_int_var(0..=10).with_name("x");
+    let y = cp_model.new_int_var(-5..=5).with_name("y");
+    let a = cp_model.new_bool_var();
+    let b = cp_model.new_bool_var();
+    cp_model.add_bool_or(&[a, b]);
+    cp_model.add_linear_le(LinearExpr::from(x) + y, 12);
+    cp_model.minimize(LinearExpr::from(x) - 3 * y);
+
+    let bytes = cp_model.export_to_bytes();
+    let proto = CpModelProto::decode(bytes.as_slice()).unwrap();
+    assert_eq!(proto.encode_to_vec(), bytes);
+
+    let mut parameters = SatParameters::new();
+    parameters.set_num_workers(4);
+
+    let bytes = parameters.export_to_bytes();
+    let proto = SatParametersProto::decode(bytes.as_slice()).unwrap();
+    assert_eq!(proto.num_workers, Some(4));
+    assert_eq!(proto.encode_to_vec(), bytes);
+}
+
+#[test]
+fn proto_parameters_round_trip() {
+    let mut parameters = SatParameters::new();
+    parameters.set_num_workers(1);
+    parameters.set_max_time_in_seconds(10.0);
+
+    let mut proto = parameters.export_to_proto();
+    assert_eq!(proto.num_workers(), 1);
+    assert_eq!(proto.max_time_in_seconds(), 10.0);
+    assert_eq!(proto.random_seed(), 1);
+    assert_eq!(proto.search_branching(), SearchBranching::AutomaticSearch);
+
+    proto.set_search_branching(SearchBranching::FixedSearch);
+    let parameters = SatParameters::from_proto(&proto).unwrap();
+    assert_eq!(parameters.export_to_proto(), proto);
+
+    let default = SatParametersProto::default();
+    assert_eq!(default.max_time_in_seconds(), f64::INFINITY);
+    assert!(default.cp_model_presolve());
+
+    // Every parameter is kept, including those without a dedicated setter.
+    let mut parameters = SatParameters::new();
+    parameters
+        .merge_from_text("symmetry_level: 4 restart_algorithms: FIXED_RESTART")
+        .unwrap();
+    let mut proto = parameters.export_to_proto();
+    assert_eq!(proto.symmetry_level(), 4);
+    assert_eq!(
+        proto.restart_algorithms,
+        [RestartAlgorithm::FixedRestart as i32]
+    );
+
+    proto.set_initial_polarity(Polarity::Random);
+    proto.cp_model_probing_level = Some(0);
+    let parameters = SatParameters::from_proto(&proto).unwrap();
+    assert_eq!(parameters.export_to_proto(), proto);
+}
@@ -0,0 +1,53 @@
+use itertools::Itertools;
+use or_tools::sat::sat_solver::{Literal, SatSolver, SatSolverStatus};
+
+#[test]
+fn incremental_sat_solver() {
+    let mut solver = SatSolver::new();
+
+    let a = solver.new_boolean_variable();
+    let b = solver.new_boolean_variable();
+    let c = solver.new_boolean_variable();
+
+    // (a or b) and (not a or c)
+    assert!(solver.add_clause(&[a.positive(), b.positive()]));
+    assert!(solver.add_clause(&[a.negative(), c.positive()]));
+
+    assert_eq!(solver.solve(), SatSolverStatus::Feasible);
+    println!("model: {:?}", solver.last_model());
+    let value = |literal| solver.value(literal).unwrap();
+    assert!(value(a.positive()) || value(b.positive()));
+    assert!(!value(a.positive()) || value(c.positive()));
+
+    // Assuming a and not c is incompatible.
+    let status = solver.solve_with_assumptions(&[a.positive(), b.negative(), c.negative()]);
+    assert_eq!(status, SatSolverStatus::AssumptionsUnsat);
+    println!("core: {:?}", solver.last_core());
+    assert!(solver.last_model().is_empty());
+    assert_eq!(
+        solver.last_core().iter().copied().sorted().collect_vec(),
+        [c.negative(), a.positive()],
+    );
+
+    // Clauses can be added between calls.
+    let d = solver.new_boolean_variable();
+    assert!(solver.add_clause(&[!c.positive(), d.positive()]));
+    assert!(solver.add_clause(&[a.positive()]));
+    assert_eq!(solver.solve(), SatSolverStatus::Feasible);
+    assert_eq!(solver.value(d.positive()), Some(true));
+
+    // a, c and d are fixed by propagation, so not d is detected as UNSAT.
+    assert!(!solver.add_clause(&[d.negative()]));
+    assert_eq!(solver.solve(), SatSolverStatus::Infeasible);
+}
+
+#[test]
+#[should_panic(expected = "unknown variable in assumptions")]
+fn sat_solver_rejects_unknown_assumption() {
+    let mut solver = SatSolver::new();
+
+    let a = solver.new_boolean_variable();
+    assert!(solver.add_clause(&[a.positive()]));
+
+    solver.solve_with_assumptions(&[Literal::from_signed_value(2)]);
+}
@@ -0,0 +1,119 @@
+use or_tools::sat::cp_model::{CpModelBuilder, CpSolverStatus};
+
+#[test]
+fn interval_sample_sat() {
+    let cp_model = CpModelBuilder::new();
+
+    let horizon = 100;
+    let start = cp_model.new_int_var(0..=horizon).with_name("start");
+    let end = cp_model.new_int_var(0..=horizon).with_name("end");
+    let interval = cp_model
+        .new_interval_var(start, 10, end + 2)
+        .with_name("interval");
+    let fixed_size = cp_model
+        .new_fixed_size_interval_var(start, 10)
+        .with_name("fixed_size");
+
+    println!("{}: {:?}", interval.name(), interval.end_expr());
+    println!("{}: {:?}", fixed_size.name(), fixed_size.end_expr());
+
+    assert_eq!(interval.name(), "interval");
+    assert_eq!(interval.size_expr().constant(), 10);
+    assert_eq!(fixed_size.start_expr().variables(), [start.index()]);
+    assert_eq!(fixed_size.end_expr().constant(), 10);
+}
+
+#[test]
+fn no_overlap_sample_sat() {
+    let cp_model = CpModelBuilder::new();
+
+    // Three weeks.
+    let horizon = 21;
+
+    // Task 0, duration 2.
+    let start_0 = cp_model.new_int_var(0..=horizon);
+    let task_0 = cp_model.new_fixed_size_interval_var(start_0, 2);
+
+    // Task 1, duration 4.
+    let start_1 = cp_model.new_int_var(0..=horizon);
+    let task_1 = cp_model.new_fixed_size_interval_var(start_1, 4);
+
+    // Task 2, duration 3.
+    let start_2 = cp_model.new_int_var(0..=horizon);
+    let task_2 = cp_model.new_fixed_size_interval_var(start_2, 3);
+
+    // Week ends.
+    let weekend_0 = cp_model.new_fixed_size_interval_var(5, 2);
+    let weekend_1 = cp_model.new_fixed_size_interval_var(12, 2);
+    let weekend_2 = cp_model.new_fixed_size_interval_var(19, 2);
+
+    // No Overlap constraint. This constraint enforces that no two intervals can
+    // overlap in time.
+    cp_model.add_no_overlap(&[task_0, task_1, task_2, weekend_0, weekend_1, weekend_2]);
+
+    // Makespan.
+    let makespan = cp_model.new_int_var(0..=horizon).with_name("makespan");
+    for task in [task_0, task_1, task_2] {
+        cp_model.add_linear_le(task.end_expr(), makespan);
+    }
+    cp_model.minimize(makespan);
+
+    // Solving part.
+    let response = cp_model.solve();
+    println!("Optimal Schedule Length: {}", response.objective_value());
+    println!("Task 0 starts at {}", response.value(&start_0));
+    println!("Task 1 starts at {}", response.value(&start_1));
+    println!("Task 2 starts at {}", response.value(&start_2));
+
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert_eq!(response.objective_value(), 11.0);
+    assert_eq!(response.value(&start_1), 7);
+}
+
+#[test]
+fn cumulative_sample_sat() {
+    let cp_model = CpModelBuilder::new();
+
+    let horizon = 6;
+    let capacity = 2;
+
+    // Three mandatory tasks of duration 3, with demands 1, 1 and 2.
+    let tasks = [1, 1, 2].map(|demand| {
+        let start = cp_model.new_int_var(0..=horizon - 3);
+        (cp_model.new_fixed_size_interval_var(start, 3), demand)
+    });
+
+    // An optional task of duration 3 and demand 1, which does not fit.
+    let presence = cp_model.new_bool_var().with_name("presence");
+    let optional_start = cp_model.new_int_var(0..=horizon - 3);
+    let optional_task = cp_model.new_optional_fixed_size_interval_var(optional_start, 3, presence);
+    assert_eq!(optional_task.presence_bool_var().index(), presence.index());
+
+    let mut cumulative = cp_model.add_cumulative(capacity);
+    for (task, demand) in tasks {
+        cumulative.add_demand(task, demand);
+    }
+    cumulative.add_demand(optional_task, 1);
+
+    cp_model.maximize(presence);
+
+    // Solving part.
+    let response = cp_model.solve();
+    for (index, (task, demand)) in tasks.iter().enumerate() {
+        println!(
+            "Task {index} (demand {demand}) starts at {}",
+            response.evaluate(task.start_expr()),
+        );
+    }
+
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert!(!response.boolean_value(&presence));
+
+    // The task with demand 2 cannot run with the other ones.
+    let [(task_0, _), (task_1, _), (task_2, _)] = tasks;
+    let start_2 = response.evaluate(task_2.start_expr());
+    for task in [task_0, task_1] {
+        let start = response.evaluate(task.start_expr());
+        assert!(start + 3 <= start_2 || start_2 + 3 <= start);
+    }
+}
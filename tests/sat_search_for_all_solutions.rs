@@ -0,0 +1,91 @@
+use itertools::Itertools;
+use or_tools::sat::{
+    cp_model::{CpModelBuilder, CpSolverStatus},
+    sat_parameters::SatParameters,
+};
+
+#[test]
+fn search_for_all_solutions_sample_sat() {
+    let cp_model = CpModelBuilder::new();
+
+    let domain = 0..=2;
+    let x = cp_model.new_int_var(domain.clone()).with_name("x");
+    let y = cp_model.new_int_var(domain.clone()).with_name("y");
+    let z = cp_model.new_int_var(domain).with_name("z");
+
+    cp_model.add_linear_ne(x, y);
+
+    let mut parameters = SatParameters::new();
+    parameters.set_num_workers(1);
+
+    let solutions = cp_model
+        .solutions(&parameters)
+        .enumerate()
+        .map(|(index, solution)| {
+            println!("Solution {index}");
+            println!("  x = {}", solution.value(&x));
+            println!("  y = {}", solution.value(&y));
+            println!("  z = {}", solution.value(&z));
+            (solution.value(&x), solution.value(&y), solution.value(&z))
+        })
+        .collect_vec();
+
+    println!("Number of solutions found: {}", solutions.len());
+    assert_eq!(solutions.len(), 18);
+    assert_eq!(solutions.iter().unique().count(), 18);
+    assert!(solutions.iter().all(|(x, y, _)| x != y));
+
+    // Dropping the iterator early stops the search.
+    let first_solutions = cp_model.solutions(&parameters).take(3).collect_vec();
+    assert_eq!(first_solutions.len(), 3);
+    assert!(first_solutions
+        .iter()
+        .all(|solution| solution.evaluate(x - y) != 0));
+}
+
+#[test]
+fn search_for_all_solutions_status() {
+    let mut parameters = SatParameters::new();
+    parameters.set_num_workers(1);
+
+    // All the solutions are enumerated.
+    let cp_model = CpModelBuilder::new();
+    let x = cp_model.new_int_var(0..=3);
+    cp_model.add_linear_ne(x, 1);
+
+    let mut solutions = cp_model.solutions(&parameters);
+    assert_eq!(solutions.status(), None);
+    assert_eq!(solutions.by_ref().count(), 3);
+    assert_eq!(solutions.status(), Some(CpSolverStatus::Optimal));
+
+    // An infeasible model is not just an empty enumeration.
+    let cp_model = CpModelBuilder::new();
+    let x = cp_model.new_int_var(0..=3);
+    cp_model.add_linear_gt(x, 3);
+
+    let mut solutions = cp_model.solutions(&parameters);
+    assert_eq!(solutions.next(), None);
+    assert_eq!(solutions.status(), Some(CpSolverStatus::Infeasible));
+}
+
+#[test]
+fn search_for_all_solutions_rejects_objective() {
+    let cp_model = CpModelBuilder::new();
+
+    let x = cp_model.new_int_var(0..=3);
+    cp_model.maximize(x);
+
+    let mut solutions = cp_model.solutions(&SatParameters::new());
+    assert_eq!(solutions.next(), None);
+    assert_eq!(solutions.status(), Some(CpSolverStatus::ModelInvalid));
+
+    // Soft constraints create an objective as well.
+    let cp_model = CpModelBuilder::new();
+
+    let b = cp_model.new_bool_var();
+    cp_model.add_soft_bool_or(&[b], 1);
+
+    let mut solutions = cp_model.solutions(&SatParameters::new());
+    assert_eq!(solutions.next(), None);
+    assert_eq!(solutions.status(), Some(CpSolverStatus::ModelInvalid));
+}
@@ -0,0 +1,30 @@
+use or_tools::sat::cp_model::{CpModelBuilder, CpSolverStatus};
+
+#[test]
+fn simple_sat_program() {
+    let cp_model = CpModelBuilder::new();
+
+    let domain = 0..=2;
+    let x = cp_model.new_int_var(domain.clone()).with_name("x");
+    let y = cp_model.new_int_var(domain.clone()).with_name("y");
+    let z = cp_model.new_int_var(domain).with_name("z");
+
+    cp_model.add_linear_ne(x, y);
+
+    // Solving part.
+    let response = cp_model.solve();
+    println!("{response:?}");
+
+    let status = response.status();
+    if status == CpSolverStatus::Optimal || status == CpSolverStatus::Feasible {
+        // Get the value of x in the solution.
+        println!("x = {}", response.value(&x));
+        println!("y = {}", response.value(&y));
+        println!("z = {}", response.value(&z));
+    } else {
+        println!("No solution found.");
+    }
+
+    assert_eq!(status, CpSolverStatus::Optimal);
+    assert_ne!(response.value(&x), response.value(&y));
+}
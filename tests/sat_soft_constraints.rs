@@ -0,0 +1,121 @@
+use or_tools::sat::cp_model::{CpModelBuilder, CpSolverStatus, LinearExpr};
+
+#[test]
+fn soft_constraints_sample_sat() {
+    let cp_model = CpModelBuilder::new();
+
+    let x = cp_model.new_int_var(0..=10).with_name("x");
+    let y = cp_model.new_int_var(0..=10).with_name("y");
+    let b = cp_model.new_bool_var().with_name("b");
+
+    // Hard constraints.
+    cp_model.add_linear_eq(LinearExpr::from(x) + y, 10);
+    cp_model.add_bool_and(&[!b]);
+
+    // Soft constraints.
+    let soft_constraints = [
+        cp_model.add_soft_linear_le(x, 3, 2).with_name("x <= 3"),
+        cp_model.add_soft_linear(y, 0..=4, 3).with_name("y in 0..4"),
+        cp_model.add_soft_bool_or(&[b], 5).with_name("b"),
+    ];
+
+    let response = cp_model.solve();
+    println!("{response:?}");
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+
+    for soft_constraint in &soft_constraints {
+        println!(
+            "{}: violated = {}, violation = {}, cost = {}",
+            soft_constraint.name(),
+            soft_constraint.is_violated(&response),
+            soft_constraint.violation(&response),
+            soft_constraint.cost(&response),
+        );
+    }
+
+    assert_eq!(response.value(&x), 6);
+    assert_eq!(response.value(&y), 4);
+    assert_eq!(response.objective_value(), 11.0);
+
+    let violations: Vec<_> = soft_constraints
+        .iter()
+        .map(|soft_constraint| {
+            (
+                soft_constraint.is_violated(&response),
+                soft_constraint.violation(&response),
+            )
+        })
+        .collect();
+    assert_eq!(violations, [(true, 3), (false, 0), (true, 1)]);
+}
+
+#[test]
+fn soft_constraints_with_objective() {
+    let cp_model = CpModelBuilder::new();
+
+    let x = cp_model.new_int_var(0..=10);
+
+    // The penalties are subtracted from the main objective.
+    cp_model.maximize(x);
+    let soft_constraint = cp_model.add_soft_linear_le(x, 5, 2);
+
+    let response = cp_model.solve();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert_eq!(response.value(&x), 5);
+    assert_eq!(response.objective_value(), 5.0);
+    assert!(!soft_constraint.is_violated(&response));
+}
+
+#[test]
+fn soft_constraints_before_objective() {
+    let cp_model = CpModelBuilder::new();
+
+    let x = cp_model.new_int_var(0..=10);
+
+    // The penalties are kept when the objective is set afterwards.
+    let soft_constraint = cp_model.add_soft_linear_le(x, 5, 2);
+    cp_model.maximize(x);
+
+    let response = cp_model.solve();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert_eq!(response.value(&x), 5);
+    assert_eq!(response.objective_value(), 5.0);
+    assert!(!soft_constraint.is_violated(&response));
+}
+
+#[test]
+fn soft_constraints_after_clear_objective() {
+    let cp_model = CpModelBuilder::new();
+
+    let x = cp_model.new_int_var(0..=10);
+
+    // Clearing the objective drops the penalties as well.
+    let soft_constraint = cp_model.add_soft_linear_le(x, 5, 2);
+    cp_model.clear_objective();
+    assert!(!cp_model.has_objective());
+    cp_model.maximize(x);
+
+    let response = cp_model.solve();
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert_eq!(response.value(&x), 10);
+    assert_eq!(response.objective_value(), 10.0);
+    assert_eq!(soft_constraint.cost(&response), 10);
+}
+
+#[test]
+#[should_panic(expected = "must be non-negative")]
+fn reject_negative_penalty() {
+    let cp_model = CpModelBuilder::new();
+
+    let b = cp_model.new_bool_var();
+    cp_model.add_soft_bool_or(&[b], -1);
+}
+
+#[test]
+#[should_panic(expected = "must fit in a variable")]
+fn reject_unbounded_violation() {
+    let cp_model = CpModelBuilder::new();
+
+    let x = cp_model.new_int_var(0..=10);
+    cp_model.add_soft_linear_ge(x, i64::MAX - 1, 1);
+}
@@ -0,0 +1,43 @@
+use or_tools::sat::cp_model::{CpModelBuilder, CpSolverStatus};
+
+#[test]
+fn solution_hinting_sample_sat() {
+    let cp_model = CpModelBuilder::new();
+
+    let domain = 0..=2;
+    let x = cp_model.new_int_var(domain.clone()).with_name("x");
+    let y = cp_model.new_int_var(domain.clone()).with_name("y");
+    let z = cp_model.new_int_var(domain).with_name("z");
+
+    cp_model.add_linear_ne(x, y);
+
+    cp_model.maximize(x + 2 * y + 3 * z);
+
+    // Solution hinting: x <- 1, y <- 2
+    cp_model.add_hint(x, 1);
+    cp_model.add_hint(y, 2);
+
+    let response = cp_model.solve();
+    println!(
+        "Maximum of objective function: {}",
+        response.objective_value()
+    );
+    println!("  x = {}", response.value(&x));
+    println!("  y = {}", response.value(&y));
+    println!("  z = {}", response.value(&z));
+
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert_eq!(response.objective_value(), 11.0);
+
+    // Warm-start a re-solve after a small change in the model.
+    let w = cp_model.new_bool_var().with_name("w");
+    cp_model.add_linear_le(x + w, 1);
+    cp_model.hint_from_response(&response);
+
+    let response = cp_model.solve();
+    println!("Re-solved objective: {}", response.objective_value());
+    println!("  w = {}", response.boolean_value(&w));
+
+    assert_eq!(response.status(), CpSolverStatus::Optimal);
+    assert_eq!(response.objective_value(), 11.0);
+}
@@ -0,0 +1,42 @@
+use or_tools::sat::{
+    cp_model::{CpModelBuilder, CpSolverStatus},
+    sat_parameters::SatParameters,
+};
+
+#[test]
+fn solve_with_time_limit_sample_sat() {
+    let cp_model = CpModelBuilder::new();
+
+    let domain = 0..=2;
+    let x = cp_model.new_int_var(domain.clone()).with_name("x");
+    let y = cp_model.new_int_var(domain.clone()).with_name("y");
+    let z = cp_model.new_int_var(domain).with_name("z");
+
+    cp_model.add_linear_ne(x, y);
+
+    // Solving part.
+    let mut parameters = SatParameters::new();
+    parameters.set_max_time_in_seconds(10.0);
+    parameters
+        .merge_from_text("num_workers: 1")
+        .expect("failed to parse parameters");
+
+    // The parser error is reported.
+    let error = parameters
+        .merge_from_text("not_a_parameter: 1")
+        .expect_err("unknown parameters should be rejected");
+    println!("{error}");
+    assert!(error.to_string().contains("not_a_parameter"));
+
+    let response = cp_model.solve_with_parameters(&parameters);
+    println!("{response:?}");
+
+    let status = response.status();
+    if status == CpSolverStatus::Optimal {
+        println!("  x = {}", response.value(&x));
+        println!("  y = {}", response.value(&y));
+        println!("  z = {}", response.value(&z));
+    }
+
+    assert_eq!(status, CpSolverStatus::Optimal);
+}
@@ -0,0 +1,51 @@
+use std::ops::ControlFlow;
+
+use or_tools::sat::{
+    cp_model::{CpModelBuilder, CpSolverStatus},
+    sat_parameters::SatParameters,
+};
+
+#[test]
+fn stop_after_n_solutions_sample_sat() {
+    let cp_model = CpModelBuilder::new();
+
+    let domain = 0..=2;
+    let x = cp_model.new_int_var(domain.clone());
+    let y = cp_model.new_int_var(domain.clone());
+    let z = cp_model.new_int_var(domain);
+
+    let mut parameters = SatParameters::new();
+    parameters.set_enumerate_all_solutions(true);
+
+    let solution_limit = 5;
+    let mut num_solutions = 0;
+    let response = cp_model.solve_with_observer(&parameters, |response| {
+        println!("Solution {num_solutions}");
+        println!("  x = {}", response.value(&x));
+        println!("  y = {}", response.value(&y));
+        println!("  z = {}", response.value(&z));
+        num_solutions += 1;
+        if num_solutions >= solution_limit {
+            println!("Stop search after {solution_limit} solutions.");
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    });
+
+    println!("Number of solutions found: {num_solutions}");
+    assert_eq!(num_solutions, solution_limit);
+    assert_eq!(response.status(), CpSolverStatus::Feasible);
+}
+
+#[test]
+#[should_panic(expected = "observer panicked")]
+fn observer_panic_is_resumed() {
+    let cp_model = CpModelBuilder::new();
+
+    let x = cp_model.new_int_var(0..=2);
+    cp_model.maximize(x);
+
+    let parameters = SatParameters::new();
+    cp_model.solve_with_observer(&parameters, |_| panic!("observer panicked"));
+}